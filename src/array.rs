@@ -0,0 +1,109 @@
+//! Functions for splitting arrays by value
+//!
+//! Since the lengths of the resulting arrays cannot be computed from generic parameters on stable,
+//! they are separate generic parameters that are usually inferred from how the result is used.
+//! Whether they add up to the length of the input is checked at compile time, when the function
+//! is monomorphized.
+
+use core::mem::ManuallyDrop;
+
+use crate::fmt::StrBuf;
+
+/// Panics at compile time if `L + R != N`.
+const fn assert_split_lengths<const N: usize, const L: usize, const R: usize>() {
+    const fn fail(n: usize, l: usize, r: usize) -> ! {
+        let mut buf = StrBuf::<128>::new();
+        buf.push_str("cannot split an array of length ");
+        buf.push_display(&n);
+        buf.push_str(" into arrays of lengths ");
+        buf.push_display(&l);
+        buf.push_str(" and ");
+        buf.push_display(&r);
+        panic!("{}", buf.as_str())
+    }
+    const {
+        match L.checked_add(R) {
+            Some(len) if len == N => {}
+            _ => fail(N, L, R),
+        }
+    }
+}
+
+/// Splits an array into two arrays of lengths `L` and `R` by value.
+///
+/// # Panics
+/// At compile time, if `L + R != N`.
+///
+/// # Example
+/// ```
+/// use const_util::array::array_split;
+/// const fn halves<T>(arr: [T; 4]) -> ([T; 2], [T; 2]) {
+///     array_split(arr)
+/// }
+/// let (lhs, rhs) = halves(["1", "2", "3", "4"].map(String::from));
+/// assert_eq!(lhs, ["1", "2"]);
+/// assert_eq!(rhs, ["3", "4"]);
+///
+/// let (lhs, rhs) = array_split::<_, 3, 1, 2>([1, 2, 3]);
+/// assert_eq!((lhs, rhs), ([1], [2, 3]));
+/// ```
+/// ```compile_fail
+/// let (lhs, rhs) = const_util::array::array_split::<_, 3, 1, 1>([1, 2, 3]);
+/// ```
+pub const fn array_split<T, const N: usize, const L: usize, const R: usize>(
+    arr: [T; N],
+) -> ([T; L], [T; R]) {
+    assert_split_lengths::<N, L, R>();
+    let arr = ManuallyDrop::new(arr);
+    let ptr = crate::mem::man_drop_ref(&arr).as_ptr();
+    // SAFETY: The array is forgotten after this and since `L + R == N`, the arrays read are
+    // disjoint parts of it
+    unsafe {
+        (
+            ptr.cast::<[T; L]>().read(),
+            ptr.add(L).cast::<[T; R]>().read(),
+        )
+    }
+}
+
+/// Splits the first element off an array by value.
+///
+/// # Panics
+/// At compile time, if `M + 1 != N`.
+///
+/// # Example
+/// ```
+/// use const_util::array::array_split_first;
+/// let (first, rest): (_, [_; 2]) = array_split_first(["1", "2", "3"].map(String::from));
+/// assert_eq!(first, "1");
+/// assert_eq!(rest, ["2", "3"]);
+/// ```
+pub const fn array_split_first<T, const N: usize, const M: usize>(arr: [T; N]) -> (T, [T; M]) {
+    assert_split_lengths::<N, 1, M>();
+    let arr = ManuallyDrop::new(arr);
+    let ptr = crate::mem::man_drop_ref(&arr).as_ptr();
+    // SAFETY: The array is forgotten after this and since `M + 1 == N`, the first element and the
+    // array of the remaining ones are disjoint parts of it
+    unsafe { (ptr.read(), ptr.add(1).cast::<[T; M]>().read()) }
+}
+
+/// Splits the last element off an array by value.
+///
+/// # Panics
+/// At compile time, if `M + 1 != N`.
+///
+/// # Example
+/// ```
+/// use const_util::array::array_split_last;
+/// let (rest, last): ([_; 2], _) = array_split_last(["1", "2", "3"].map(String::from));
+/// assert_eq!(rest, ["1", "2"]);
+/// assert_eq!(last, "3");
+/// ```
+pub const fn array_split_last<T, const N: usize, const M: usize>(arr: [T; N]) -> ([T; M], T) {
+    assert_split_lengths::<N, M, 1>();
+    let arr = ManuallyDrop::new(arr);
+    let ptr = crate::mem::man_drop_ref(&arr).as_ptr();
+    // SAFETY: The array is forgotten after this and since `M + 1 == N`, the array of the first
+    // elements and the last element are disjoint parts of it
+    unsafe { (ptr.cast::<[T; M]>().read(), ptr.add(M).read()) }
+}
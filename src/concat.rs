@@ -54,19 +54,6 @@ pub const fn concat_strs<'a, Strs: Const<Type = &'a [&'a str]>>() -> &'static st
     }
 }
 
-#[rustversion::since(1.87)]
-const fn copy_from_slice<T: Copy>(src: &[T], dst: &mut [T]) {
-    dst.copy_from_slice(src);
-}
-#[rustversion::before(1.87)]
-const fn copy_from_slice<T: Copy>(src: &[T], dst: &mut [T]) {
-    assert!(src.len() == dst.len());
-    // SAFETY: T: Copy. This is literally how copy_from_slice is implemented.
-    unsafe {
-        core::ptr::copy_nonoverlapping(src.as_ptr(), dst.as_mut_ptr(), src.len());
-    }
-}
-
 macro_rules! generate_slice_concat {
     ($T:ty, $input:ty, $default:expr) => {{
         use generic_upper_bound as gub;
@@ -89,7 +76,7 @@ macro_rules! generate_slice_concat {
                 while let [first, rest @ ..] = slices {
                     let lhs;
                     (lhs, out_slice) = out_slice.split_at_mut(first.len());
-                    copy_from_slice(first, lhs);
+                    crate::polyfill::slice_copy_from_slice(lhs, first);
                     slices = rest;
                 }
                 out
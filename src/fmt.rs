@@ -0,0 +1,284 @@
+//! Const formatting of values into fixed-size buffers
+//!
+//! Since `const` panics can only display a single `&str`, messages containing values have to be
+//! rendered into a buffer first. [`StrBuf`] is such a buffer and [`ConstDisplay`] describes how a
+//! value is rendered into it.
+
+pub(crate) const USIZE_STR_LEN: usize = {
+    let mut len = 1;
+    let mut n = usize::MAX;
+    while n >= 10 {
+        n /= 10;
+        len += 1;
+    }
+    len
+};
+/// Writes `s` to the start of `to` and returns the remaining part of `to`.
+pub(crate) const fn write_str<'a>(s: &str, to: &'a mut [u8]) -> &'a mut [u8] {
+    let (lhs, rem) = to.split_at_mut(s.len());
+    crate::polyfill::slice_copy_from_slice(lhs, s.as_bytes());
+    rem
+}
+/// Writes the decimal representation of `n` to the start of `to` and returns the remaining part
+/// of `to`.
+pub(crate) const fn write_u128(mut n: u128, to: &mut [u8]) -> &mut [u8] {
+    let mut len = 1;
+    let mut rem = n;
+    while rem >= 10 {
+        rem /= 10;
+        len += 1;
+    }
+    let (mut digits, rem) = to.split_at_mut(len);
+    while let [rest @ .., slot] = digits {
+        *slot = (n % 10) as u8 + b'0';
+        n /= 10;
+        digits = rest;
+    }
+    rem
+}
+
+#[doc(hidden)]
+pub mod __private {
+    /// Describes how a [`ConstDisplay`](super::ConstDisplay) implementor is rendered.
+    ///
+    /// Each variant states what it requires of the implementing type, see the safety section of
+    /// [`ConstDisplay`](super::ConstDisplay).
+    pub enum DisplayRepr {
+        /// An unsigned integer of the given size, which must be 1, 2, 4, 8 or 16. The implementor
+        /// must have the size and at least the alignment of the unsigned integer of that size,
+        /// and all of its bytes must be initialized.
+        Unsigned(usize),
+        /// A signed integer of the given size, which must be 1, 2, 4, 8 or 16. The implementor
+        /// must have the size and at least the alignment of the signed integer of that size, and
+        /// all of its bytes must be initialized.
+        Signed(usize),
+        /// The implementor must have the layout of [`bool`] and every value of it must be a valid
+        /// `bool`.
+        Bool,
+        /// The implementor must have the layout of [`char`] and every value of it must be a valid
+        /// `char`.
+        Char,
+        /// The implementor must have the layout of `&str` and every value of it must be a valid
+        /// `&str`.
+        Str,
+        /// The implementor must have a size of at least 1 and its first byte must always be
+        /// initialized. That byte is used as an index into the slice, so a value must be less
+        /// than the length of the slice, otherwise rendering it panics. This is satisfied by
+        /// `#[repr(u8)]` enums without fields whose discriminants are the indices of the variant
+        /// names in the slice.
+        Enum(&'static [&'static str]),
+    }
+}
+use __private::DisplayRepr;
+
+/// Values that can be rendered into a [`StrBuf`] in `const` contexts.
+///
+/// This is implemented for the integer primitives, [`bool`], [`char`] and `&str`. User enums can
+/// implement it using [`const_display_enum!`].
+///
+/// # Safety
+/// `REPR` is used to read values of `Self` through raw pointers, so `Self` must satisfy the
+/// requirements documented on the variant of `DisplayRepr` that `REPR` is set to. In short, it
+/// must have the layout and validity of the primitive that `REPR` names, or, for enums, its first
+/// byte must always be initialized.
+///
+/// Implementing this trait using [`const_display_enum!`] always satisfies these requirements and
+/// is the only supported way to implement it outside of this crate.
+///
+/// [`const_display_enum!`]: crate::const_display_enum
+pub unsafe trait ConstDisplay {
+    #[doc(hidden)]
+    const REPR: DisplayRepr;
+}
+macro_rules! impl_const_display {
+    ($($ty:ty => $repr:expr;)*) => {$(
+        // SAFETY: `REPR` describes the type
+        unsafe impl ConstDisplay for $ty {
+            const REPR: DisplayRepr = $repr;
+        }
+    )*};
+}
+impl_const_display! {
+    u8 => DisplayRepr::Unsigned(1);
+    u16 => DisplayRepr::Unsigned(2);
+    u32 => DisplayRepr::Unsigned(4);
+    u64 => DisplayRepr::Unsigned(8);
+    u128 => DisplayRepr::Unsigned(16);
+    usize => DisplayRepr::Unsigned(size_of::<usize>());
+    i8 => DisplayRepr::Signed(1);
+    i16 => DisplayRepr::Signed(2);
+    i32 => DisplayRepr::Signed(4);
+    i64 => DisplayRepr::Signed(8);
+    i128 => DisplayRepr::Signed(16);
+    isize => DisplayRepr::Signed(size_of::<isize>());
+    bool => DisplayRepr::Bool;
+    char => DisplayRepr::Char;
+    &str => DisplayRepr::Str;
+}
+
+/// Implements [`ConstDisplay`] for an enum without fields, rendering the name of its variants.
+///
+/// The macro accepts the enum definition and adds `#[repr(u8)]` to it. Explicit discriminants
+/// are not supported.
+///
+/// # Example
+/// ```
+/// use const_util::{const_display_enum, result::expect_ok_display};
+/// const_display_enum! {
+///     #[derive(Debug, Clone, Copy)]
+///     pub enum ParseError {
+///         Empty,
+///         InvalidDigit,
+///     }
+/// }
+/// const fn parse(s: &str) -> Result<u32, ParseError> {
+///     Err(ParseError::InvalidDigit)
+/// }
+/// let res = std::panic::catch_unwind(|| expect_ok_display(parse("x"), "Parsing failed"));
+/// assert_eq!(
+///     *res.unwrap_err().downcast::<String>().unwrap(),
+///     "Parsing failed: InvalidDigit",
+/// );
+/// ```
+#[macro_export]
+macro_rules! const_display_enum {
+    (
+        $(#[$meta:meta])*
+        $vis:vis enum $name:ident {
+            $($(#[$vmeta:meta])* $variant:ident),* $(,)?
+        }
+    ) => {
+        $(#[$meta])*
+        #[repr(u8)]
+        $vis enum $name {
+            $($(#[$vmeta])* $variant),*
+        }
+        // SAFETY: `Self` is a `#[repr(u8)]` enum without fields and with implicit discriminants,
+        // which are the indices of the variant names
+        unsafe impl $crate::fmt::ConstDisplay for $name {
+            const REPR: $crate::fmt::__private::DisplayRepr =
+                $crate::fmt::__private::DisplayRepr::Enum(&[$($crate::__mac::core::stringify!($variant)),*]);
+        }
+    };
+}
+
+/// A string buffer with a fixed capacity that can be written to in `const` contexts.
+///
+/// Writes that exceed the capacity are truncated at a `char` boundary.
+///
+/// # Example
+/// ```
+/// use const_util::fmt::StrBuf;
+/// const MSG: StrBuf<32> = {
+///     let mut buf = StrBuf::new();
+///     buf.push_str("index ");
+///     buf.push_display(&-12i32);
+///     buf.push_str(" is ");
+///     buf.push_display(&true);
+///     buf
+/// };
+/// assert_eq!(MSG.as_str(), "index -12 is true");
+/// ```
+#[derive(Clone, Copy)]
+pub struct StrBuf<const N: usize> {
+    buf: [u8; N],
+    len: usize,
+    truncated: bool,
+}
+impl<const N: usize> StrBuf<N> {
+    /// Creates an empty buffer.
+    pub const fn new() -> Self {
+        Self {
+            buf: [0; N],
+            len: 0,
+            truncated: false,
+        }
+    }
+    /// Returns the contents of the buffer.
+    pub const fn as_str(&self) -> &str {
+        // SAFETY: The buffer only ever contains valid UTF-8 up to `len`
+        unsafe { core::str::from_utf8_unchecked(self.buf.split_at(self.len).0) }
+    }
+    /// Returns whether a write to the buffer had to be truncated.
+    pub const fn is_truncated(&self) -> bool {
+        self.truncated
+    }
+    /// Appends `s` to the buffer, truncating it if it does not fit.
+    pub const fn push_str(&mut self, s: &str) {
+        let rem = self.buf.split_at_mut(self.len).1;
+        let mut len = s.len();
+        if len > rem.len() {
+            self.truncated = true;
+            len = rem.len();
+            // Find a char boundary
+            while len > 0 && (s.as_bytes()[len] as i8) < -0x40 {
+                len -= 1;
+            }
+        }
+        // SAFETY: `len` is a char boundary
+        write_str(unsafe { crate::str::split_at_unchecked(s, len).0 }, rem);
+        self.len += len;
+    }
+    /// Appends the rendered value to the buffer, truncating it if it does not fit.
+    pub const fn push_display<T: ConstDisplay>(&mut self, value: &T) {
+        let ptr = core::ptr::from_ref(value).cast::<u8>();
+        // SAFETY: `ConstDisplay` is implemented such that `REPR` describes the pointee
+        unsafe {
+            match T::REPR {
+                DisplayRepr::Unsigned(size) => self.push_unsigned(match size {
+                    1 => ptr.read() as u128,
+                    2 => ptr.cast::<u16>().read() as u128,
+                    4 => ptr.cast::<u32>().read() as u128,
+                    8 => ptr.cast::<u64>().read() as u128,
+                    _ => ptr.cast::<u128>().read(),
+                }),
+                DisplayRepr::Signed(size) => self.push_signed(match size {
+                    1 => ptr.cast::<i8>().read() as i128,
+                    2 => ptr.cast::<i16>().read() as i128,
+                    4 => ptr.cast::<i32>().read() as i128,
+                    8 => ptr.cast::<i64>().read() as i128,
+                    _ => ptr.cast::<i128>().read(),
+                }),
+                DisplayRepr::Bool => self.push_str(if ptr.cast::<bool>().read() {
+                    "true"
+                } else {
+                    "false"
+                }),
+                DisplayRepr::Char => {
+                    self.push_str(ptr.cast::<char>().read().encode_utf8(&mut [0; 4]))
+                }
+                DisplayRepr::Str => self.push_str(ptr.cast::<&str>().read()),
+                DisplayRepr::Enum(names) => self.push_str(names[ptr.read() as usize]),
+            }
+        }
+    }
+    const fn push_unsigned(&mut self, n: u128) {
+        let mut digits = [0; 39];
+        let rem_len = write_u128(n, &mut digits).len();
+        // SAFETY: The digits are ASCII
+        self.push_str(unsafe {
+            core::str::from_utf8_unchecked(digits.split_at(digits.len() - rem_len).0)
+        })
+    }
+    const fn push_signed(&mut self, n: i128) {
+        if n < 0 {
+            self.push_str("-");
+        }
+        self.push_unsigned(n.unsigned_abs())
+    }
+}
+impl<const N: usize> Default for StrBuf<N> {
+    fn default() -> Self {
+        Self::new()
+    }
+}
+impl<const N: usize> core::fmt::Debug for StrBuf<N> {
+    fn fmt(&self, f: &mut core::fmt::Formatter<'_>) -> core::fmt::Result {
+        core::fmt::Debug::fmt(self.as_str(), f)
+    }
+}
+impl<const N: usize> core::fmt::Display for StrBuf<N> {
+    fn fmt(&self, f: &mut core::fmt::Formatter<'_>) -> core::fmt::Result {
+        f.write_str(self.as_str())
+    }
+}
@@ -5,36 +5,62 @@
 //! Provides stable const implementations for some things missing from the standard library.
 //!
 //! Currently implemented are
-//! - Functions in [`result`](crate::result) to unwrap [`Result`](core::result::Result)s with generics or drop glue
+//! - Functions in [`result`](crate::result) to unwrap and combine [`Result`](core::result::Result)s with generics or drop glue
+//! - Functions in [`option`](crate::option) to unwrap and modify [`Option`](core::option::Option)s with generics or drop glue
 //! - Functions in the [`concat`](crate::concat) module to concat const strings and byte slices.
+//! - Functions in [`set`](crate::set) to perform set operations on const slices
+//! - [`const_try`](crate::const_try) for `?`-style propagation of `Err` and `None` in const fns
 //! - [`destruct_tuple`](crate::destruct_tuple) to destructure tuples with generic types or types with drop glue in them
+//! - [`destruct_struct`](crate::destruct_struct) to move fields out of structs with generics or drop glue
+//! - [`destruct_array`](crate::destruct_array) and functions in [`array`](crate::array) to move elements out of arrays with generics or drop glue
+//! - [`tuple_append`](crate::tuple_append), [`tuple_concat`](crate::tuple_concat) and the other macros in [`tuple`](crate::tuple) to reshape tuples with generics or drop glue
+//! - [`expect_variant`](crate::expect_variant)/[`try_variant`](crate::try_variant) to move fields out of enum variants with generics or drop glue
+//! - [`match_owned`](crate::match_owned) to match enums with generics or drop glue by value
 //! - [`nonnull_from`](crate::mem::nonnull_from) to create [`NonNull`](core::ptr::NonNull)s from mutable and regular references
 //!   conveniently
 //! - [`man_drop_ref`](crate::mem::man_drop_ref)/[`man_drop_mut`](crate::mem::man_drop_mut) as a workaround for the lack of const
 //!   [`Deref`](core::ops::Deref) implementations
+//! - Functions in [`mem`](crate::mem) to convert `ManuallyDrop`s and `MaybeUninit`s of arrays, slices and pairs into arrays, slices and pairs of them
+//! - [`ArrayBuilder`](crate::mem::ArrayBuilder) to build arrays of generic values one element at a time
+//! - [`LayoutBuilder`](crate::mem::LayoutBuilder) to compute the layouts of `#[repr(C)]` structs with generic fields
+//! - [`Pod`](crate::mem::Pod)/[`Zeroable`](crate::mem::Zeroable) with [`pod_struct`](crate::pod_struct) to convert plain data to and from bytes. Unlike arrays, tuples deliberately do not implement `Pod`, since their layout is unspecified
+//! - [`read_le`](crate::mem::read_le), [`write_be`](crate::mem::write_be) and related functions to read and write integers in byte buffers
+//! - [`transmute_checked`](crate::mem::transmute_checked), [`transmute_ref`](crate::mem::transmute_ref) and [`transmute_mut`](crate::mem::transmute_mut) to transmute between generic types, checked at compile time
+//! - [`TransparentWrapper`](crate::mem::TransparentWrapper) and [`transparent_wrapper`](crate::transparent_wrapper) to cast references and slices to and from `#[repr(transparent)]` wrappers
+//! - [`ptr_from`](crate::ptr::ptr_from), [`nonnull_from_option`](crate::ptr::nonnull_from_option) and checked pointer arithmetic in [`ptr`](crate::ptr), generic over [`Reference`](crate::ptr::Reference)
+//! - Functions in [`polyfill`](crate::polyfill) that call standard library functions which are only `const` on newer compilers, with fallbacks for older ones
+//! - [`ConstDisplay`](crate::fmt::ConstDisplay) and [`StrBuf`](crate::fmt::StrBuf) to render values into `const` panic messages
+//! - [`ConstError`](crate::result::ConstError), a `Copy` error type with static context for `const` code
 //! - Functions in [`slice`](crate::slice) to take subslices using ranges
+//! - Const iterators over slices and `str`s in [`slice`](crate::slice) and [`str`](crate::str)
 
 pub extern crate type_const;
 pub use type_const::{value_of, Const};
 
+pub mod array;
 pub mod concat;
+pub mod fmt;
 pub mod mem;
+pub mod option;
+pub mod polyfill;
+pub mod ptr;
 pub mod result;
+pub mod set;
 pub mod slice;
-
-#[doc(hidden)]
-#[macro_export]
-macro_rules! __infer {
-    ($($_:tt)*) => {
-        _
-    };
-}
+pub mod str;
+pub mod tuple;
 
 /// Allows destructuring tuples in `const` contexts, regardless of items having drop glue.
 ///
 /// This is mainly useful to allow generic functions to return tuples without being then stuck
 /// without a way to pull them back apart.
 ///
+/// Every field is bound by an identifier, optionally preceded by `mut`, by a nested tuple pattern
+/// in parentheses or ignored using `_`. Identifiers and `_` may be followed by a type ascription.
+/// Ignored fields are forgotten, which is why ignoring a field whose type has drop glue (as
+/// determined by [`needs_drop`](core::mem::needs_drop)) causes a compile error when the
+/// surrounding code is monomorphized.
+///
 /// # Example
 /// ```
 /// use const_util::*;
@@ -46,19 +72,605 @@ macro_rules! __infer {
 ///     pair_to_arr((String::from("ABC"), String::new())),
 ///     ["ABC", ""],
 /// );
+///
+/// const fn sum(nested: (u8, (u32, u32), &str)) -> u32 {
+///     destruct_tuple! { a, (mut b, c: u32), _: &str in nested }
+///     b += c;
+///     b + a as u32
+/// }
+/// assert_eq!(sum((1, (2, 3), "")), 6);
+/// ```
+///
+/// Ignoring a field with drop glue fails to compile:
+/// ```compile_fail
+/// use const_util::*;
+/// const fn first<T>(pair: (T, String)) -> T {
+///     destruct_tuple! { a, _ in pair }
+///     a
+/// }
+/// first((1, String::new()));
 /// ```
 #[macro_export]
 macro_rules! destruct_tuple {
-    ($($field:ident),* in $tup:expr) => {
-        let __tup: ($($crate::__infer!($field),)*) = $tup;
+    ($($tt:tt)*) => {
+        $crate::__destruct_tuple_split!([] $($tt)*);
+    };
+}
+#[doc(hidden)]
+#[macro_export]
+macro_rules! __destruct_tuple_split {
+    ([$($pat:tt)*] in $tup:expr) => {
+        $crate::__destruct_tuple!([] [] [] [] [$($pat)*] $tup);
+    };
+    ([$($pat:tt)*] $next:tt $($rest:tt)*) => {
+        $crate::__destruct_tuple_split!([$($pat)* $next] $($rest)*);
+    };
+}
+/// Parses the fields of a tuple pattern, keeping track of the enclosing patterns in a stack.
+///
+/// The state is `[stack] [types] [patterns] [statements] [remaining input] expression`.
+#[doc(hidden)]
+#[macro_export]
+macro_rules! __destruct_tuple {
+    // Done
+    ([] [$($ty:tt)*] [$($pat:tt)*] [$($stmt:tt)*] [] $tup:expr) => {
+        let __tup: ($($ty)*) = $tup;
         let __tup = $crate::__mac::core::mem::ManuallyDrop::new(__tup);
-        let ($($field),*) = $crate::mem::man_drop_ref(&__tup);
-        // SAFETY: The tuple is forgotten after this
-        $(let $field = unsafe { $crate::__mac::core::ptr::read($field) };)*
+        let ($($pat)*) = $crate::mem::man_drop_ref(&__tup);
+        // SAFETY: The tuple is forgotten after this and every bound field is read exactly once
+        $($stmt)*
+    };
+    // End of a nested pattern
+    (
+        [{[$($oty:tt)*] [$($opat:tt)*] [$($orest:tt)*]} $($stack:tt)*]
+        [$($ty:tt)*] [$($pat:tt)*] $stmts:tt [] $tup:expr
+    ) => {
+        $crate::__destruct_tuple!(
+            [$($stack)*] [$($oty)* ($($ty)*),] [$($opat)* ($($pat)*),] $stmts [$($orest)*] $tup
+        );
+    };
+    (
+        $stack:tt [$($ty:tt)*] [$($pat:tt)*] [$($stmt:tt)*]
+        [_ $(: $fty:ty)? $(, $($rest:tt)*)?] $tup:expr
+    ) => {
+        $crate::__destruct_tuple!(
+            $stack
+            [$($ty)* $crate::__ty_or_infer!($($fty)?),]
+            [$($pat)* __ignored,]
+            [$($stmt)* $crate::__mac::assert_no_drop_glue(__ignored);]
+            [$($($rest)*)?] $tup
+        );
+    };
+    (
+        $stack:tt [$($ty:tt)*] [$($pat:tt)*] [$($stmt:tt)*]
+        [mut $field:ident $(: $fty:ty)? $(, $($rest:tt)*)?] $tup:expr
+    ) => {
+        $crate::__destruct_tuple!(
+            $stack
+            [$($ty)* $crate::__ty_or_infer!($($fty)?),]
+            [$($pat)* $field,]
+            [$($stmt)* let mut $field = unsafe { $crate::__mac::core::ptr::read($field) };]
+            [$($($rest)*)?] $tup
+        );
+    };
+    (
+        $stack:tt [$($ty:tt)*] [$($pat:tt)*] [$($stmt:tt)*]
+        [$field:ident $(: $fty:ty)? $(, $($rest:tt)*)?] $tup:expr
+    ) => {
+        $crate::__destruct_tuple!(
+            $stack
+            [$($ty)* $crate::__ty_or_infer!($($fty)?),]
+            [$($pat)* $field,]
+            [$($stmt)* let $field = unsafe { $crate::__mac::core::ptr::read($field) };]
+            [$($($rest)*)?] $tup
+        );
+    };
+    // Start of a nested pattern
+    (
+        [$($stack:tt)*] $ty:tt $pat:tt $stmts:tt
+        [($($inner:tt)*) $(, $($rest:tt)*)?] $tup:expr
+    ) => {
+        $crate::__destruct_tuple!(
+            [{$ty $pat [$($($rest)*)?]} $($stack)*] [] [] $stmts [$($inner)*] $tup
+        );
+    };
+}
+#[doc(hidden)]
+#[macro_export]
+macro_rules! __ty_or_infer {
+    () => {
+        _
+    };
+    ($ty:ty) => {
+        $ty
+    };
+}
+
+/// Allows destructuring structs by value in `const` contexts, regardless of their fields having
+/// drop glue.
+///
+/// Both structs with named fields (`Path { field, field: binding }`) and tuple structs
+/// (`Path(binding, binding)`) are supported. Bindings may be preceded by `mut` or replaced by `_`
+/// to ignore the field. As with [`destruct_tuple!`], ignored fields are forgotten and ignoring a
+/// field with drop glue causes a compile error when the surrounding code is monomorphized.
+///
+/// Since `..` is not supported, every field has to be mentioned. This also means that structs with
+/// private fields and `#[non_exhaustive]` structs from other crates cannot be destructured. If the
+/// struct implements [`Drop`], its destructor is not run. The value must be passed by value,
+/// passing a reference causes a compile error.
+///
+/// # Example
+/// ```
+/// use const_util::destruct_struct;
+/// struct Point<T> {
+///     x: T,
+///     y: T,
+///     tag: u8,
+/// }
+/// struct Wrapper<T>(T, ());
+/// const fn into_pair<T>(p: Point<Wrapper<T>>) -> [T; 2] {
+///     destruct_struct! { Point { x, y: mut py, tag: _ } in p }
+///     destruct_struct! { Wrapper(x, _) in x }
+///     destruct_struct! { Wrapper(y, _) in py }
+///     [x, y]
+/// }
+/// let p = Point {
+///     x: Wrapper(String::from("a"), ()),
+///     y: Wrapper(String::from("b"), ()),
+///     tag: 0,
+/// };
+/// assert_eq!(into_pair(p), ["a", "b"]);
+/// ```
+///
+/// Leaving out a field fails to compile:
+/// ```compile_fail
+/// use const_util::destruct_struct;
+/// struct Point<T> {
+///     x: T,
+///     y: T,
+/// }
+/// const fn x<T: Copy>(p: Point<T>) -> T {
+///     destruct_struct! { Point { x } in p }
+///     x
+/// }
+/// ```
+#[macro_export]
+macro_rules! destruct_struct {
+    ($($path:ident)::+ { $($fields:tt)* } in $ex:expr) => {
+        $crate::__destruct_fields!(named [$($path)::+] [] [] [$($fields)*] $ex);
+    };
+    ($($path:ident)::+ ( $($fields:tt)* ) in $ex:expr) => {
+        $crate::__destruct_fields!(tuple [$($path)::+] [] [] [$($fields)*] $ex);
+    };
+}
+/// Parses the fields of a struct or array pattern.
+///
+/// The state is `kind [path] [patterns] [statements] [remaining input] expression`.
+#[doc(hidden)]
+#[macro_export]
+macro_rules! __destruct_fields {
+    ($kind:ident [$($path:tt)*] [$($pat:tt)*] [$($stmt:tt)*] [] $ex:expr) => {
+        let __val = $crate::__mac::core::mem::ManuallyDrop::new($ex);
+        let __ref = $crate::mem::man_drop_ref(&__val);
+        $crate::__mac::assert_not_reference(__ref);
+        let $crate::__fields_pattern!($kind [$($path)*] $($pat)*) = __ref;
+        // SAFETY: The value is held in a `ManuallyDrop` that is not used afterwards and every bound
+        // field is read exactly once
+        $($stmt)*
+    };
+    // Named fields
+    (
+        named $path:tt [$($pat:tt)*] [$($stmt:tt)*]
+        [$field:ident: _ $(, $($rest:tt)*)?] $ex:expr
+    ) => {
+        $crate::__destruct_fields!(
+            named $path
+            [$($pat)* $field: __ignored,]
+            [$($stmt)* $crate::__mac::assert_no_drop_glue(__ignored);]
+            [$($($rest)*)?] $ex
+        );
+    };
+    (
+        named $path:tt [$($pat:tt)*] [$($stmt:tt)*]
+        [$field:ident: mut $bind:ident $(, $($rest:tt)*)?] $ex:expr
+    ) => {
+        $crate::__destruct_fields!(
+            named $path
+            [$($pat)* $field: $bind,]
+            [$($stmt)* let mut $bind = unsafe { $crate::__mac::core::ptr::read($bind) };]
+            [$($($rest)*)?] $ex
+        );
+    };
+    (
+        named $path:tt [$($pat:tt)*] [$($stmt:tt)*]
+        [$field:ident: $bind:ident $(, $($rest:tt)*)?] $ex:expr
+    ) => {
+        $crate::__destruct_fields!(
+            named $path
+            [$($pat)* $field: $bind,]
+            [$($stmt)* let $bind = unsafe { $crate::__mac::core::ptr::read($bind) };]
+            [$($($rest)*)?] $ex
+        );
+    };
+    (
+        named $path:tt [$($pat:tt)*] [$($stmt:tt)*]
+        [mut $field:ident $(, $($rest:tt)*)?] $ex:expr
+    ) => {
+        $crate::__destruct_fields!(
+            named $path
+            [$($pat)* $field,]
+            [$($stmt)* let mut $field = unsafe { $crate::__mac::core::ptr::read($field) };]
+            [$($($rest)*)?] $ex
+        );
+    };
+    (
+        named $path:tt [$($pat:tt)*] [$($stmt:tt)*]
+        [$field:ident $(, $($rest:tt)*)?] $ex:expr
+    ) => {
+        $crate::__destruct_fields!(
+            named $path
+            [$($pat)* $field,]
+            [$($stmt)* let $field = unsafe { $crate::__mac::core::ptr::read($field) };]
+            [$($($rest)*)?] $ex
+        );
+    };
+    // Array rest patterns
+    (
+        array $path:tt [$($pat:tt)*] [$($stmt:tt)*]
+        [.. $(, $($rest:tt)*)?] $ex:expr
+    ) => {
+        $crate::__destruct_fields!(
+            array $path
+            [$($pat)* __ignored @ ..,]
+            [$($stmt)* $crate::__mac::assert_no_drop_glue(__ignored);]
+            [$($($rest)*)?] $ex
+        );
+    };
+    (
+        array $path:tt [$($pat:tt)*] [$($stmt:tt)*]
+        [mut $bind:ident @ .. $(, $($rest:tt)*)?] $ex:expr
+    ) => {
+        $crate::__destruct_fields!(
+            array $path
+            [$($pat)* $bind @ ..,]
+            [$($stmt)* let mut $bind = unsafe { $crate::__mac::core::ptr::read($bind) };]
+            [$($($rest)*)?] $ex
+        );
+    };
+    (
+        array $path:tt [$($pat:tt)*] [$($stmt:tt)*]
+        [$bind:ident @ .. $(, $($rest:tt)*)?] $ex:expr
+    ) => {
+        $crate::__destruct_fields!(
+            array $path
+            [$($pat)* $bind @ ..,]
+            [$($stmt)* let $bind = unsafe { $crate::__mac::core::ptr::read($bind) };]
+            [$($($rest)*)?] $ex
+        );
+    };
+    // Tuple and array elements
+    (
+        $kind:ident $path:tt [$($pat:tt)*] [$($stmt:tt)*]
+        [_ $(, $($rest:tt)*)?] $ex:expr
+    ) => {
+        $crate::__destruct_fields!(
+            $kind $path
+            [$($pat)* __ignored,]
+            [$($stmt)* $crate::__mac::assert_no_drop_glue(__ignored);]
+            [$($($rest)*)?] $ex
+        );
+    };
+    (
+        $kind:ident $path:tt [$($pat:tt)*] [$($stmt:tt)*]
+        [mut $bind:ident $(, $($rest:tt)*)?] $ex:expr
+    ) => {
+        $crate::__destruct_fields!(
+            $kind $path
+            [$($pat)* $bind,]
+            [$($stmt)* let mut $bind = unsafe { $crate::__mac::core::ptr::read($bind) };]
+            [$($($rest)*)?] $ex
+        );
+    };
+    (
+        $kind:ident $path:tt [$($pat:tt)*] [$($stmt:tt)*]
+        [$bind:ident $(, $($rest:tt)*)?] $ex:expr
+    ) => {
+        $crate::__destruct_fields!(
+            $kind $path
+            [$($pat)* $bind,]
+            [$($stmt)* let $bind = unsafe { $crate::__mac::core::ptr::read($bind) };]
+            [$($($rest)*)?] $ex
+        );
+    };
+}
+#[doc(hidden)]
+#[macro_export]
+macro_rules! __fields_pattern {
+    (named [$($path:tt)*] $($pat:tt)*) => {
+        $($path)* { $($pat)* }
+    };
+    (tuple [$($path:tt)*] $($pat:tt)*) => {
+        $($path)* ( $($pat)* )
+    };
+    (array [] $($pat:tt)*) => {
+        [ $($pat)* ]
+    };
+}
+
+/// Allows destructuring arrays by value in `const` contexts, regardless of their elements having
+/// drop glue.
+///
+/// Elements are bound by identifiers, optionally preceded by `mut`, or ignored using `_`. A single
+/// `binding @ ..` binds the remaining elements as an array, while `..` ignores them. As with
+/// [`destruct_tuple!`], ignored elements are forgotten and ignoring elements with drop glue causes
+/// a compile error when the surrounding code is monomorphized.
+///
+/// Like array patterns, this only works if the length of the array is known. For arrays of generic
+/// length, see the functions in [`array`](crate::array).
+///
+/// # Example
+/// ```
+/// use const_util::destruct_array;
+/// const fn rotate<T>(arr: [T; 4]) -> [T; 4] {
+///     destruct_array! { [first, rest @ ..] in arr }
+///     destruct_array! { [a, b, c] in rest }
+///     [a, b, c, first]
+/// }
+/// assert_eq!(rotate(["1", "2", "3", "4"].map(String::from)), ["2", "3", "4", "1"]);
+///
+/// const fn last_two(arr: [u8; 5]) -> (u8, u8) {
+///     destruct_array! { [.., a, _] in arr }
+///     destruct_array! { [_, .., mut b] in arr }
+///     b += 1;
+///     (a, b)
+/// }
+/// assert_eq!(last_two([1, 2, 3, 4, 5]), (4, 6));
+/// ```
+#[macro_export]
+macro_rules! destruct_array {
+    ([$($elems:tt)*] in $ex:expr) => {
+        $crate::__destruct_fields!(array [] [] [] [$($elems)*] $ex);
+    };
+}
+
+/// Allows matching enums by value in `const` contexts, regardless of their payloads having drop
+/// glue.
+///
+/// The syntax is similar to that of a `match` expression, except that the value is followed by a
+/// comma and every arm must be a single variant that binds all of its fields by name. Tuple
+/// variants, struct variants (`Path::Variant { field, field: binding }`) and unit variants are
+/// supported. Arms must be separated by commas. The fields are moved out of the value and bound by
+/// value, meaning that no drop glue needs to run for the value itself.
+///
+/// Fields can be ignored using `_` (`Some(_)`, `Path::Variant { field: _ }`). Since ignored
+/// fields would be leaked, this causes a compile error if their type has drop glue. For the same
+/// reason, there are no catch-all arms: an arm consisting of a single identifier must name a unit
+/// variant.
+///
+/// If the enum implements [`Drop`], its destructor is not run. The value must be passed by value,
+/// passing a reference causes a compile error.
+///
+/// # Example
+/// ```
+/// use const_util::match_owned;
+/// enum Either<L, R> {
+///     Left(L),
+///     Right(R),
+/// }
+/// const fn flip<L, R>(either: Either<L, R>) -> Either<R, L> {
+///     match_owned!(either,
+///         Either::Left(l) => Either::Right(l),
+///         Either::Right(r) => Either::Left(r),
+///     )
+/// }
+/// const fn left_or<T>(opt: Option<T>, default: T) -> Either<T, T> {
+///     match_owned!(opt,
+///         Some(value) => {
+///             core::mem::forget(default);
+///             Either::Left(value)
+///         },
+///         None => Either::Right(default),
+///     )
+/// }
+/// assert!(matches!(flip(Either::<String, ()>::Left(String::new())), Either::Right(_)));
+/// assert!(matches!(left_or(None, String::new()), Either::Right(_)));
+///
+/// const fn into_left<L>(either: Either<L, u8>) -> Option<L> {
+///     match_owned!(either,
+///         Either::Left(l) => Some(l),
+///         Either::Right(_) => None,
+///     )
+/// }
+/// assert_eq!(into_left(Either::<String, _>::Right(1)), None);
+/// ```
+/// Ignored fields and catch-all arms that would leak values with drop glue are rejected:
+/// ```compile_fail
+/// const fn into_left<L, R>(either: Either<L, R>) -> Option<L> {
+///     match_owned!(either,
+///         Either::Left(l) => Some(l),
+///         Either::Right(_) => None,
+///     )
+/// }
+/// # use const_util::match_owned;
+/// # enum Either<L, R> { Left(L), Right(R) }
+/// # into_left(Either::<u8, String>::Right(String::new()));
+/// ```
+/// ```compile_fail
+/// use const_util::match_owned;
+/// const fn len(opt: Option<String>) -> usize {
+///     match_owned!(opt,
+///         Some(s) => s.len(),
+///         other => 0,
+///     )
+/// }
+/// ```
+#[macro_export]
+macro_rules! match_owned {
+    ($ex:expr, $($arms:tt)*) => {{
+        let __val = $crate::__mac::core::mem::ManuallyDrop::new($ex);
+        let __ref = $crate::mem::man_drop_ref(&__val);
+        $crate::__mac::assert_not_reference(__ref);
+        $crate::__match_owned_arms!(__ref, [] $($arms)*)
+    }};
+}
+#[doc(hidden)]
+#[macro_export]
+macro_rules! __match_owned_arms {
+    ($ref:ident, [$($out:tt)*] $(,)?) => {
+        match $ref {
+            $($out)*
+        }
+    };
+    (
+        $ref:ident, [$($out:tt)*]
+        $($variant:ident)::+ ($($field:tt),* $(,)?) => $body:expr
+        $(, $($rest:tt)*)?
+    ) => {
+        $crate::__match_owned_fields!(
+            tuple $ref [$($out)*] [$($variant)::+] [] [] [$([$field])*] $body,
+            $($($rest)*)?
+        )
+    };
+    (
+        $ref:ident, [$($out:tt)*]
+        $($variant:ident)::+ { $($field:ident $(: $bind:tt)?),* $(,)? } => $body:expr
+        $(, $($rest:tt)*)?
+    ) => {
+        $crate::__match_owned_fields!(
+            named $ref [$($out)*] [$($variant)::+] [] [] [$([$field $(: $bind)?])*] $body,
+            $($($rest)*)?
+        )
+    };
+    (
+        $ref:ident, [$($out:tt)*]
+        $($variant:ident)::+ => $body:expr
+        $(, $($rest:tt)*)?
+    ) => {
+        $crate::__match_owned_arms!(
+            $ref,
+            // The braces make sure that the path is a unit variant and not a catch-all binding
+            [$($out)* $($variant)::+ {} => $body,]
+            $($($rest)*)?
+        )
+    };
+}
+// Builds the pattern and the statements moving the fields out of one arm of `match_owned!`, one
+// field at a time such that every ignored field gets its own `__ignored` binding
+#[doc(hidden)]
+#[macro_export]
+macro_rules! __match_owned_fields {
+    (
+        tuple $ref:ident [$($out:tt)*] [$($path:tt)*] [$($pats:tt)*] [$($stmts:tt)*] []
+        $body:expr, $($rest:tt)*
+    ) => {
+        $crate::__match_owned_arms!(
+            $ref,
+            [$($out)* $($path)* ($($pats)*) => {
+                $($stmts)*
+                $body
+            }]
+            $($rest)*
+        )
+    };
+    (
+        named $ref:ident [$($out:tt)*] [$($path:tt)*] [$($pats:tt)*] [$($stmts:tt)*] []
+        $body:expr, $($rest:tt)*
+    ) => {
+        $crate::__match_owned_arms!(
+            $ref,
+            [$($out)* $($path)* { $($pats)* } => {
+                $($stmts)*
+                $body
+            }]
+            $($rest)*
+        )
+    };
+    (
+        $kind:ident $ref:ident $out:tt $path:tt [$($pats:tt)*] [$($stmts:tt)*]
+        [[_] $($fields:tt)*] $($tail:tt)*
+    ) => {
+        $crate::__match_owned_fields!(
+            $kind $ref $out $path
+            [$($pats)* __ignored,]
+            [$($stmts)* $crate::__mac::assert_no_drop_glue(__ignored);]
+            [$($fields)*] $($tail)*
+        )
+    };
+    (
+        $kind:ident $ref:ident $out:tt $path:tt [$($pats:tt)*] [$($stmts:tt)*]
+        [[$field:ident : _] $($fields:tt)*] $($tail:tt)*
+    ) => {
+        $crate::__match_owned_fields!(
+            $kind $ref $out $path
+            [$($pats)* $field: __ignored,]
+            [$($stmts)* $crate::__mac::assert_no_drop_glue(__ignored);]
+            [$($fields)*] $($tail)*
+        )
+    };
+    (
+        $kind:ident $ref:ident $out:tt $path:tt [$($pats:tt)*] [$($stmts:tt)*]
+        [[$field:ident : $bind:ident] $($fields:tt)*] $($tail:tt)*
+    ) => {
+        $crate::__match_owned_fields!(
+            $kind $ref $out $path
+            [$($pats)* $field: $bind,]
+            [$($stmts)* $crate::__read_fields!([$bind]);]
+            [$($fields)*] $($tail)*
+        )
+    };
+    (
+        $kind:ident $ref:ident $out:tt $path:tt [$($pats:tt)*] [$($stmts:tt)*]
+        [[$field:ident] $($fields:tt)*] $($tail:tt)*
+    ) => {
+        $crate::__match_owned_fields!(
+            $kind $ref $out $path
+            [$($pats)* $field,]
+            [$($stmts)* $crate::__read_fields!([$field]);]
+            [$($fields)*] $($tail)*
+        )
+    };
+}
+#[doc(hidden)]
+#[macro_export]
+macro_rules! __read_fields {
+    ($([$($bind:tt)*]),*) => {
+        // SAFETY: The matched value is held by value in a `ManuallyDrop` that is not used
+        // afterwards and every field is read exactly once
+        $(let $($bind)* = unsafe { $crate::__mac::core::ptr::read($($bind)*) };)*
+    };
+}
+
+#[doc(hidden)]
+#[macro_export]
+macro_rules! __first {
+    ($first:tt $($_:tt)*) => {
+        $first
     };
 }
 
 #[doc(hidden)]
 pub mod __mac {
     pub use core;
+
+    pub trait NotReference<M> {}
+    impl<T: ?Sized> NotReference<()> for T {}
+    impl<T: ?Sized> NotReference<u8> for &T {}
+    impl<T: ?Sized> NotReference<u16> for &mut T {}
+    /// Causes an ambiguity error if `T` is a reference
+    pub const fn assert_not_reference<T: ?Sized + NotReference<M>, M>(_: &T) {}
+
+    /// Fails to compile when monomorphized with a `T` that has drop glue
+    pub const fn assert_no_drop_glue<T>(_: &T) {
+        const {
+            assert!(
+                !core::mem::needs_drop::<T>(),
+                "ignored value has drop glue and would be leaked",
+            )
+        }
+    }
+
+    /// Panics if `W` does not have the same size and alignment as `I`
+    pub const fn assert_wrapper_layout<W: crate::mem::TransparentWrapper<I>, I>() {
+        crate::mem::assert_wrapper_layout::<W, I>()
+    }
 }
@@ -1,7 +1,8 @@
 //! Functions related to [`core::mem`] and [`core::ptr`]
 
+use crate::slice::SliceIndexError;
 use core::{
-    mem::ManuallyDrop,
+    mem::{offset_of, ManuallyDrop, MaybeUninit},
     ptr::{self, NonNull},
 };
 
@@ -34,6 +35,299 @@ pub const fn man_drop_mut<T>(man: &mut ManuallyDrop<T>) -> &mut T {
     unsafe { &mut *ptr::from_mut(man).cast() }
 }
 
+/// Converts a reference to a `ManuallyDrop` array into a reference to an array of `ManuallyDrop`s.
+///
+/// # Example
+/// ```
+/// use const_util::mem::man_drop_array_ref;
+/// use core::mem::ManuallyDrop;
+/// let arr = ManuallyDrop::new([1, 2]);
+/// let [a, b] = man_drop_array_ref(&arr);
+/// assert_eq!((**a, **b), (1, 2));
+/// ```
+pub const fn man_drop_array_ref<T, const N: usize>(
+    man: &ManuallyDrop<[T; N]>,
+) -> &[ManuallyDrop<T>; N] {
+    // SAFETY: repr(transparent)
+    unsafe { &*ptr::from_ref(man).cast() }
+}
+/// Converts a mutable reference to a `ManuallyDrop` array into a mutable reference to an array of
+/// `ManuallyDrop`s.
+///
+/// # Example
+/// ```
+/// use const_util::mem::man_drop_array_mut;
+/// use core::mem::ManuallyDrop;
+/// let mut arr = ManuallyDrop::new([1, 2]);
+/// *man_drop_array_mut(&mut arr)[0] = 3;
+/// assert_eq!(*arr, [3, 2]);
+/// ```
+pub const fn man_drop_array_mut<T, const N: usize>(
+    man: &mut ManuallyDrop<[T; N]>,
+) -> &mut [ManuallyDrop<T>; N] {
+    // SAFETY: repr(transparent)
+    unsafe { &mut *ptr::from_mut(man).cast() }
+}
+/// Converts a reference to a `ManuallyDrop` slice into a reference to a slice of `ManuallyDrop`s.
+///
+/// # Example
+/// ```
+/// use const_util::mem::man_drop_slice_ref;
+/// use core::mem::ManuallyDrop;
+/// let arr = ManuallyDrop::new([1, 2]);
+/// let slice: &ManuallyDrop<[i32]> = &arr;
+/// assert_eq!(*man_drop_slice_ref(slice)[1], 2);
+/// ```
+pub const fn man_drop_slice_ref<T>(man: &ManuallyDrop<[T]>) -> &[ManuallyDrop<T>] {
+    // SAFETY: repr(transparent)
+    unsafe { &*(ptr::from_ref(man) as *const [ManuallyDrop<T>]) }
+}
+/// Converts a mutable reference to a `ManuallyDrop` slice into a mutable reference to a slice of
+/// `ManuallyDrop`s.
+///
+/// # Example
+/// ```
+/// use const_util::mem::man_drop_slice_mut;
+/// use core::mem::ManuallyDrop;
+/// let mut arr = ManuallyDrop::new([1, 2]);
+/// let slice: &mut ManuallyDrop<[i32]> = &mut arr;
+/// *man_drop_slice_mut(slice)[1] = 3;
+/// assert_eq!(*arr, [1, 3]);
+/// ```
+pub const fn man_drop_slice_mut<T>(man: &mut ManuallyDrop<[T]>) -> &mut [ManuallyDrop<T>] {
+    // SAFETY: repr(transparent)
+    unsafe { &mut *(ptr::from_mut(man) as *mut [ManuallyDrop<T>]) }
+}
+/// Converts a `ManuallyDrop` array into an array of `ManuallyDrop`s.
+///
+/// This allows moving the elements out of the array one at a time.
+///
+/// # Example
+/// ```
+/// use const_util::mem::man_drop_into_array;
+/// use core::mem::ManuallyDrop;
+/// const fn first<T: Copy, const N: usize>(arr: [T; N]) -> T {
+///     let arr = man_drop_into_array(ManuallyDrop::new(arr));
+///     ManuallyDrop::into_inner(arr[0])
+/// }
+/// assert_eq!(first([1, 2]), 1);
+/// ```
+pub const fn man_drop_into_array<T, const N: usize>(
+    man: ManuallyDrop<[T; N]>,
+) -> [ManuallyDrop<T>; N] {
+    // SAFETY: repr(transparent)
+    unsafe { transmute_checked(man) }
+}
+/// Converts an array of `ManuallyDrop`s into a `ManuallyDrop` array.
+///
+/// # Example
+/// ```
+/// use const_util::mem::man_drop_from_array;
+/// use core::mem::ManuallyDrop;
+/// let arr = man_drop_from_array([ManuallyDrop::new(1), ManuallyDrop::new(2)]);
+/// assert_eq!(ManuallyDrop::into_inner(arr), [1, 2]);
+/// ```
+pub const fn man_drop_from_array<T, const N: usize>(
+    arr: [ManuallyDrop<T>; N],
+) -> ManuallyDrop<[T; N]> {
+    // SAFETY: repr(transparent)
+    unsafe { transmute_checked(arr) }
+}
+/// Converts a `ManuallyDrop` pair into a pair of `ManuallyDrop`s.
+///
+/// # Example
+/// ```
+/// use const_util::mem::man_drop_unzip;
+/// use core::mem::ManuallyDrop;
+/// const fn second<A, B: Copy>(pair: (A, B)) -> B {
+///     let (_, b) = man_drop_unzip(ManuallyDrop::new(pair));
+///     ManuallyDrop::into_inner(b)
+/// }
+/// assert_eq!(second((String::new(), 1)), 1);
+/// ```
+pub const fn man_drop_unzip<A, B>(man: ManuallyDrop<(A, B)>) -> (ManuallyDrop<A>, ManuallyDrop<B>) {
+    let (a, b) = man_drop_ref(&man);
+    // SAFETY: The fields are read exactly once and `man` does not drop them
+    unsafe {
+        (
+            ManuallyDrop::new(ptr::read(a)),
+            ManuallyDrop::new(ptr::read(b)),
+        )
+    }
+}
+/// Converts a pair of `ManuallyDrop`s into a `ManuallyDrop` pair.
+///
+/// # Example
+/// ```
+/// use const_util::mem::man_drop_zip;
+/// use core::mem::ManuallyDrop;
+/// let pair = man_drop_zip(ManuallyDrop::new(1), ManuallyDrop::new('a'));
+/// assert_eq!(*pair, (1, 'a'));
+/// ```
+pub const fn man_drop_zip<A, B>(a: ManuallyDrop<A>, b: ManuallyDrop<B>) -> ManuallyDrop<(A, B)> {
+    ManuallyDrop::new((ManuallyDrop::into_inner(a), ManuallyDrop::into_inner(b)))
+}
+
+/// Converts a reference to a `MaybeUninit` array into a reference to an array of `MaybeUninit`s.
+///
+/// # Example
+/// ```
+/// use const_util::mem::uninit_array_ref;
+/// use core::mem::MaybeUninit;
+/// let arr = MaybeUninit::new([1, 2]);
+/// assert_eq!(unsafe { uninit_array_ref(&arr)[1].assume_init() }, 2);
+/// ```
+pub const fn uninit_array_ref<T, const N: usize>(
+    uninit: &MaybeUninit<[T; N]>,
+) -> &[MaybeUninit<T>; N] {
+    // SAFETY: `MaybeUninit<[T; N]>` and `[MaybeUninit<T>; N]` have the same layout
+    unsafe { &*ptr::from_ref(uninit).cast() }
+}
+/// Converts a mutable reference to a `MaybeUninit` array into a mutable reference to an array of
+/// `MaybeUninit`s.
+///
+/// This allows initializing the array one element at a time.
+///
+/// # Example
+/// ```
+/// use const_util::mem::uninit_array_mut;
+/// use core::mem::MaybeUninit;
+/// const SQUARES: [usize; 4] = {
+///     let mut arr = MaybeUninit::uninit();
+///     let elems = uninit_array_mut(&mut arr);
+///     let mut i = 0;
+///     while i < elems.len() {
+///         elems[i] = MaybeUninit::new(i * i);
+///         i += 1;
+///     }
+///     // SAFETY: All elements were initialized
+///     unsafe { arr.assume_init() }
+/// };
+/// assert_eq!(SQUARES, [0, 1, 4, 9]);
+/// ```
+pub const fn uninit_array_mut<T, const N: usize>(
+    uninit: &mut MaybeUninit<[T; N]>,
+) -> &mut [MaybeUninit<T>; N] {
+    // SAFETY: `MaybeUninit<[T; N]>` and `[MaybeUninit<T>; N]` have the same layout
+    unsafe { &mut *ptr::from_mut(uninit).cast() }
+}
+/// Converts a slice of `MaybeUninit`s into a reference to a `MaybeUninit` array if its length is
+/// `N`.
+///
+/// # Example
+/// ```
+/// use const_util::mem::uninit_slice_as_array;
+/// use core::mem::MaybeUninit;
+/// let slice = [MaybeUninit::new(1), MaybeUninit::new(2)];
+/// assert!(uninit_slice_as_array::<_, 3>(&slice).is_none());
+/// let arr = uninit_slice_as_array::<_, 2>(&slice).unwrap();
+/// assert_eq!(unsafe { arr.assume_init() }, [1, 2]);
+/// ```
+pub const fn uninit_slice_as_array<T, const N: usize>(
+    slice: &[MaybeUninit<T>],
+) -> Option<&MaybeUninit<[T; N]>> {
+    if slice.len() != N {
+        return None;
+    }
+    // SAFETY: The slice has length `N` and the same layout as `MaybeUninit<[T; N]>`
+    Some(unsafe { &*slice.as_ptr().cast() })
+}
+/// Converts a mutable slice of `MaybeUninit`s into a mutable reference to a `MaybeUninit` array
+/// if its length is `N`.
+///
+/// # Example
+/// ```
+/// use const_util::mem::uninit_slice_as_array_mut;
+/// use core::mem::MaybeUninit;
+/// let mut slice = [MaybeUninit::uninit(); 3];
+/// let arr = uninit_slice_as_array_mut::<_, 2>(&mut slice[1..]).unwrap();
+/// arr.write([1, 2]);
+/// assert_eq!(unsafe { slice[2].assume_init() }, 2);
+/// ```
+pub const fn uninit_slice_as_array_mut<T, const N: usize>(
+    slice: &mut [MaybeUninit<T>],
+) -> Option<&mut MaybeUninit<[T; N]>> {
+    if slice.len() != N {
+        return None;
+    }
+    // SAFETY: The slice has length `N` and the same layout as `MaybeUninit<[T; N]>`
+    Some(unsafe { &mut *slice.as_mut_ptr().cast() })
+}
+/// Converts a `MaybeUninit` array into an array of `MaybeUninit`s.
+///
+/// # Example
+/// ```
+/// use const_util::mem::uninit_into_array;
+/// use core::mem::MaybeUninit;
+/// let [a, _] = uninit_into_array(MaybeUninit::new([1, 2]));
+/// assert_eq!(unsafe { a.assume_init() }, 1);
+/// ```
+pub const fn uninit_into_array<T, const N: usize>(
+    uninit: MaybeUninit<[T; N]>,
+) -> [MaybeUninit<T>; N] {
+    // SAFETY: `MaybeUninit<[T; N]>` and `[MaybeUninit<T>; N]` have the same layout
+    unsafe { transmute_checked(uninit) }
+}
+/// Converts an array of `MaybeUninit`s into a `MaybeUninit` array.
+///
+/// # Example
+/// ```
+/// use const_util::mem::uninit_from_array;
+/// use core::mem::MaybeUninit;
+/// let arr = uninit_from_array([MaybeUninit::new(1), MaybeUninit::new(2)]);
+/// assert_eq!(unsafe { arr.assume_init() }, [1, 2]);
+/// ```
+pub const fn uninit_from_array<T, const N: usize>(arr: [MaybeUninit<T>; N]) -> MaybeUninit<[T; N]> {
+    // SAFETY: `MaybeUninit<[T; N]>` and `[MaybeUninit<T>; N]` have the same layout
+    unsafe { transmute_checked(arr) }
+}
+/// Converts a `MaybeUninit` pair into a pair of `MaybeUninit`s.
+///
+/// # Example
+/// ```
+/// use const_util::mem::uninit_unzip;
+/// use core::mem::MaybeUninit;
+/// let (a, _) = uninit_unzip(MaybeUninit::new((1, 'a')));
+/// assert_eq!(unsafe { a.assume_init() }, 1);
+/// ```
+pub const fn uninit_unzip<A, B>(uninit: MaybeUninit<(A, B)>) -> (MaybeUninit<A>, MaybeUninit<B>) {
+    let ptr = uninit.as_ptr().cast::<u8>();
+    // SAFETY: The fields are in bounds and any bytes are valid for `MaybeUninit`
+    unsafe {
+        (
+            ptr.add(offset_of!((A, B), 0))
+                .cast::<MaybeUninit<A>>()
+                .read(),
+            ptr.add(offset_of!((A, B), 1))
+                .cast::<MaybeUninit<B>>()
+                .read(),
+        )
+    }
+}
+/// Converts a pair of `MaybeUninit`s into a `MaybeUninit` pair.
+///
+/// # Example
+/// ```
+/// use const_util::mem::uninit_zip;
+/// use core::mem::MaybeUninit;
+/// let pair = uninit_zip(MaybeUninit::new(1), MaybeUninit::new('a'));
+/// assert_eq!(unsafe { pair.assume_init() }, (1, 'a'));
+/// ```
+pub const fn uninit_zip<A, B>(a: MaybeUninit<A>, b: MaybeUninit<B>) -> MaybeUninit<(A, B)> {
+    let mut uninit = MaybeUninit::<(A, B)>::uninit();
+    let ptr = uninit.as_mut_ptr().cast::<u8>();
+    // SAFETY: The fields are in bounds
+    unsafe {
+        ptr.add(offset_of!((A, B), 0))
+            .cast::<MaybeUninit<A>>()
+            .write(a);
+        ptr.add(offset_of!((A, B), 1))
+            .cast::<MaybeUninit<B>>()
+            .write(b);
+    }
+    uninit
+}
+
 /// Converts `&T` or `&mut T` into `NonNull<T>`.
 ///
 /// This function is a const version of the `From<&T>` and `From<&mut T>` implementations of
@@ -59,8 +353,8 @@ pub const fn man_drop_mut<T>(man: &mut ManuallyDrop<T>) -> &mut T {
 /// unsafe { nonnull_from(&mut x).write(2) };
 /// assert_eq!(unsafe { nonnull_from(&x).read() }, 2);
 /// ```
-pub const fn nonnull_from<T: ?Sized>(src: impl hidden::Reference<Referee = T>) -> NonNull<T> {
-    const fn doit<T: ?Sized, R: hidden::Reference<Referee = T>>(src: R) -> NonNull<R::Referee> {
+pub const fn nonnull_from<T: ?Sized>(src: impl crate::ptr::Reference<Referee = T>) -> NonNull<T> {
+    const fn doit<T: ?Sized, R: crate::ptr::Reference<Referee = T>>(src: R) -> NonNull<R::Referee> {
         let ptr: *mut T = {
             let src = ManuallyDrop::new(src);
             if R::MUTABLE {
@@ -78,21 +372,1113 @@ pub const fn nonnull_from<T: ?Sized>(src: impl hidden::Reference<Referee = T>) -
     }
     doit(src)
 }
+/// Panics with a message describing the layouts of `Src` and `Dst`.
+#[track_caller]
+const fn layout_mismatch<Src, Dst>(message: &str) -> ! {
+    let mut buf = crate::fmt::StrBuf::<256>::new();
+    buf.push_str(message);
+    buf.push_str(": source type has size ");
+    buf.push_display(&size_of::<Src>());
+    buf.push_str(" and alignment ");
+    buf.push_display(&align_of::<Src>());
+    buf.push_str(", destination type has size ");
+    buf.push_display(&size_of::<Dst>());
+    buf.push_str(" and alignment ");
+    buf.push_display(&align_of::<Dst>());
+    panic!("{}", buf.as_str())
+}
+
+/// Reinterprets the bits of a value of type `Src` as a value of type `Dst`.
+///
+/// Unlike [`core::mem::transmute`], this function can be used with generic types. Instead of
+/// checking the sizes before monomorphization, it fails to compile when it is monomorphized with
+/// types of different sizes.
+///
+/// # Safety
+/// See [`core::mem::transmute`].
+///
+/// # Example
+/// ```
+/// use const_util::mem::transmute_checked;
+/// const fn to_bytes<T: Copy>(arr: [[T; 2]; 2]) -> [T; 4] {
+///     // SAFETY: Arrays are laid out contiguously
+///     unsafe { transmute_checked(arr) }
+/// }
+/// assert_eq!(to_bytes([[1, 2], [3, 4]]), [1, 2, 3, 4]);
+/// ```
+/// ```compile_fail
+/// // Fails with "cannot transmute between types of different sizes: source type has size 4 and
+/// // alignment 4, destination type has size 8 and alignment 8"
+/// let _: u64 = unsafe { const_util::mem::transmute_checked(1u32) };
+/// ```
+pub const unsafe fn transmute_checked<Src, Dst>(src: Src) -> Dst {
+    const {
+        if size_of::<Src>() != size_of::<Dst>() {
+            layout_mismatch::<Src, Dst>("cannot transmute between types of different sizes")
+        }
+    }
+    let src = ManuallyDrop::new(src);
+    // SAFETY: `Src` and `Dst` have the same size, the rest is guaranteed by the caller
+    unsafe { core::mem::transmute_copy(&src) }
+}
+
+/// Reinterprets a reference to `Src` as a reference to `Dst`.
+///
+/// This function fails to compile when it is monomorphized with types of different sizes or if
+/// `Dst` has a greater alignment than `Src`.
+///
+/// # Safety
+/// The referenced value must be a valid value of type `Dst` and must remain one while the
+/// returned reference is in use. `Dst` must only have interior mutability where `Src` does.
+///
+/// # Example
+/// ```
+/// use const_util::mem::transmute_ref;
+/// const fn as_arr<T>(pair: &[[T; 1]; 2]) -> &[T; 2] {
+///     // SAFETY: Arrays are laid out contiguously
+///     unsafe { transmute_ref(pair) }
+/// }
+/// assert_eq!(as_arr(&[[String::new()], [String::from("a")]]), &["", "a"]);
+/// ```
+/// ```compile_fail
+/// let _: &u32 = unsafe { const_util::mem::transmute_ref(&[0u8; 4]) };
+/// ```
+pub const unsafe fn transmute_ref<Src, Dst>(src: &Src) -> &Dst {
+    const { assert_ref_layouts::<Src, Dst>() }
+    // SAFETY: `Dst` fits into the allocation of `src` and is sufficiently aligned, the rest is
+    // guaranteed by the caller
+    unsafe { &*ptr::from_ref(src).cast() }
+}
+
+/// Reinterprets a mutable reference to `Src` as a mutable reference to `Dst`.
+///
+/// This function fails to compile when it is monomorphized with types of different sizes or if
+/// `Dst` has a greater alignment than `Src`.
+///
+/// # Safety
+/// The referenced value must be a valid value of type `Dst`. Any value of type `Dst` written
+/// through the returned reference must be a valid value of type `Src`.
+///
+/// # Example
+/// ```
+/// use const_util::mem::transmute_mut;
+/// let mut x = [1u32, 2];
+/// // SAFETY: Every bit pattern is a valid `u32` and `[u16; 4]`
+/// let halves: &mut [u16; 4] = unsafe { transmute_mut(&mut x) };
+/// halves.swap(0, 2);
+/// halves.swap(1, 3);
+/// assert_eq!(x, [2, 1]);
+/// ```
+pub const unsafe fn transmute_mut<Src, Dst>(src: &mut Src) -> &mut Dst {
+    const { assert_ref_layouts::<Src, Dst>() }
+    // SAFETY: `Dst` fits into the allocation of `src` and is sufficiently aligned, the rest is
+    // guaranteed by the caller
+    unsafe { &mut *ptr::from_mut(src).cast() }
+}
+
+const fn assert_ref_layouts<Src, Dst>() {
+    if size_of::<Src>() != size_of::<Dst>() {
+        layout_mismatch::<Src, Dst>(
+            "cannot transmute between references to types of different sizes",
+        )
+    }
+    if align_of::<Src>() < align_of::<Dst>() {
+        layout_mismatch::<Src, Dst>(
+            "cannot transmute a reference to a type with a greater alignment",
+        )
+    }
+}
+
+/// Types that are `#[repr(transparent)]` wrappers around `Inner`.
+///
+/// This allows casting references and slices between the wrapper and the inner type in `const`
+/// contexts using [`wrap_ref`], [`wrap_mut`], [`peel_ref`], [`peel_mut`], [`wrap_slice`] and
+/// [`peel_slice`]. These functions fail to compile when they are monomorphized with types that do
+/// not have the same size and alignment.
+///
+/// Implementations are provided for [`ManuallyDrop`], [`Wrapping`](core::num::Wrapping),
+/// [`Saturating`](core::num::Saturating) and [`Reverse`](core::cmp::Reverse). User types can
+/// be declared with an implementation, or existing types can implement it, using
+/// [`transparent_wrapper!`](crate::transparent_wrapper).
+/// [`MaybeUninit`](core::mem::MaybeUninit) does not implement it, since converting
+/// `&mut T` to `&mut MaybeUninit<T>` would allow writing uninitialized bytes to a `T` and
+/// peeling a `MaybeUninit<T>` would allow reading them.
+///
+/// # Safety
+/// `Self` must be `#[repr(transparent)]` with `Inner` as its only non-zero-sized field. Every valid
+/// `Inner` must be a valid `Self` and vice versa.
+pub unsafe trait TransparentWrapper<Inner> {}
+
+macro_rules! impl_transparent_wrapper {
+    ($($ty:ident),*) => {$(
+        // SAFETY: `$ty<T>` is a `#[repr(transparent)]` wrapper around `T` without invariants
+        unsafe impl<T> TransparentWrapper<T> for $ty<T> {}
+    )*};
+}
+use core::{cmp::Reverse, num::Saturating, num::Wrapping};
+impl_transparent_wrapper!(ManuallyDrop, Wrapping, Saturating, Reverse);
+
+/// Declares a struct with a single field and implements [`TransparentWrapper`] for it, or
+/// implements it for an existing type.
+///
+/// When given a struct definition, the macro adds `#[repr(transparent)]` to it. Tuple structs and
+/// structs with a named field are supported, as are type parameters without bounds. The struct
+/// should not have invariants other than those of its field, since [`TransparentWrapper`] allows
+/// converting between references to the struct and to its field.
+///
+/// Existing types, including ones with bounds and where clauses, are supported using
+/// `unsafe impl Wrapper: Inner`. Since the macro cannot check the definition of the type, the
+/// caller has to uphold the safety requirements of [`TransparentWrapper`]. For types without
+/// generic parameters, the size and alignment are checked where the macro is invoked. Otherwise,
+/// they are checked when the functions using [`TransparentWrapper`] are monomorphized.
+///
+/// # Example
+/// ```
+/// use const_util::{mem::{peel_slice, wrap_ref}, transparent_wrapper};
+/// transparent_wrapper! {
+///     #[derive(Debug, PartialEq)]
+///     pub struct Meters(pub f64);
+/// }
+/// transparent_wrapper! {
+///     pub struct Labeled<T> {
+///         pub value: T,
+///     }
+/// }
+/// const DIST: &Meters = wrap_ref(&1.5);
+/// assert_eq!(DIST, &Meters(1.5));
+/// const VALUES: &[u8] = peel_slice(&[Labeled { value: 1 }, Labeled { value: 2 }]);
+/// assert_eq!(VALUES, [1, 2]);
+/// ```
+/// ```
+/// use const_util::{mem::wrap_slice, transparent_wrapper};
+/// #[repr(transparent)]
+/// pub struct Celsius(f32);
+/// // SAFETY: `Celsius` is `#[repr(transparent)]` around `f32` and has no invariants
+/// transparent_wrapper!(unsafe impl Celsius: f32);
+///
+/// #[repr(transparent)]
+/// pub struct Id<T: Copy>(T);
+/// // SAFETY: `Id<T>` is `#[repr(transparent)]` around `T` and has no invariants
+/// transparent_wrapper!(unsafe impl<T: Copy> Id<T>: T where T: Eq);
+///
+/// const IDS: &[Id<u8>] = wrap_slice(&[1, 2]);
+/// assert_eq!(IDS.len(), 2);
+/// ```
+/// ```compile_fail
+/// use const_util::transparent_wrapper;
+/// pub struct Pair(u16, u16);
+/// transparent_wrapper!(unsafe impl Pair: u16);
+/// ```
+#[macro_export]
+macro_rules! transparent_wrapper {
+    (
+        unsafe impl<$($param:ident $(: $bound:path)?),* $(,)?> $ty:ty : $inner:ty
+        $(where $($where:tt)*)?
+    ) => {
+        // SAFETY: Guaranteed by the caller
+        unsafe impl<$($param $(: $bound)?),*> $crate::mem::TransparentWrapper<$inner> for $ty
+        $(where $($where)*)? {}
+    };
+    (unsafe impl $ty:ty : $inner:ty) => {
+        // SAFETY: Guaranteed by the caller
+        unsafe impl $crate::mem::TransparentWrapper<$inner> for $ty {}
+        const _: () = $crate::__mac::assert_wrapper_layout::<$ty, $inner>();
+    };
+    (
+        $(#[$meta:meta])*
+        $vis:vis struct $name:ident $(<$($param:ident),* $(,)?>)? (
+            $(#[$fmeta:meta])* $fvis:vis $inner:ty $(,)?
+        );
+    ) => {
+        $(#[$meta])*
+        #[repr(transparent)]
+        $vis struct $name $(<$($param),*>)? ($(#[$fmeta])* $fvis $inner);
+        // SAFETY: `Self` is a `#[repr(transparent)]` struct with `$inner` as its only field
+        unsafe impl $(<$($param),*>)? $crate::mem::TransparentWrapper<$inner>
+            for $name $(<$($param),*>)? {}
+    };
+    (
+        $(#[$meta:meta])*
+        $vis:vis struct $name:ident $(<$($param:ident),* $(,)?>)? {
+            $(#[$fmeta:meta])* $fvis:vis $field:ident: $inner:ty $(,)?
+        }
+    ) => {
+        $(#[$meta])*
+        #[repr(transparent)]
+        $vis struct $name $(<$($param),*>)? {
+            $(#[$fmeta])* $fvis $field: $inner,
+        }
+        // SAFETY: `Self` is a `#[repr(transparent)]` struct with `$inner` as its only field
+        unsafe impl $(<$($param),*>)? $crate::mem::TransparentWrapper<$inner>
+            for $name $(<$($param),*>)? {}
+    };
+}
+
+pub(crate) const fn assert_wrapper_layout<W: TransparentWrapper<I>, I>() {
+    if size_of::<W>() != size_of::<I>() || align_of::<W>() != align_of::<I>() {
+        layout_mismatch::<I, W>("transparent wrapper has a different layout than its inner type")
+    }
+}
+/// Converts a reference to the inner type into a reference to the wrapper.
+///
+/// # Example
+/// ```
+/// use const_util::mem::wrap_ref;
+/// use core::num::Wrapping;
+/// const MAX: &Wrapping<u8> = wrap_ref(&u8::MAX);
+/// assert_eq!(*MAX + Wrapping(1), Wrapping(0));
+/// ```
+pub const fn wrap_ref<W: TransparentWrapper<I>, I>(inner: &I) -> &W {
+    const { assert_wrapper_layout::<W, I>() }
+    // SAFETY: `W` is a transparent wrapper around `I`
+    unsafe { &*ptr::from_ref(inner).cast() }
+}
+/// Converts a mutable reference to the inner type into a mutable reference to the wrapper.
+///
+/// # Example
+/// ```
+/// use const_util::mem::wrap_mut;
+/// use core::num::Wrapping;
+/// let mut x = u8::MAX;
+/// *wrap_mut::<Wrapping<u8>, _>(&mut x) += 1;
+/// assert_eq!(x, 0);
+/// ```
+pub const fn wrap_mut<W: TransparentWrapper<I>, I>(inner: &mut I) -> &mut W {
+    const { assert_wrapper_layout::<W, I>() }
+    // SAFETY: `W` is a transparent wrapper around `I`
+    unsafe { &mut *ptr::from_mut(inner).cast() }
+}
+/// Converts a reference to the wrapper into a reference to the inner type.
+///
+/// # Example
+/// ```
+/// use const_util::mem::peel_ref;
+/// use core::cmp::Reverse;
+/// assert_eq!(peel_ref(&Reverse(1)), &1);
+/// ```
+pub const fn peel_ref<W: TransparentWrapper<I>, I>(wrapper: &W) -> &I {
+    const { assert_wrapper_layout::<W, I>() }
+    // SAFETY: `W` is a transparent wrapper around `I`
+    unsafe { &*ptr::from_ref(wrapper).cast() }
+}
+/// Converts a mutable reference to the wrapper into a mutable reference to the inner type.
+///
+/// # Example
+/// ```
+/// use const_util::mem::peel_mut;
+/// use core::num::Wrapping;
+/// let mut x = Wrapping(1);
+/// *peel_mut(&mut x) = 2;
+/// assert_eq!(x, Wrapping(2));
+/// ```
+pub const fn peel_mut<W: TransparentWrapper<I>, I>(wrapper: &mut W) -> &mut I {
+    const { assert_wrapper_layout::<W, I>() }
+    // SAFETY: `W` is a transparent wrapper around `I`
+    unsafe { &mut *ptr::from_mut(wrapper).cast() }
+}
+/// Converts a slice of the inner type into a slice of the wrapper.
+///
+/// # Example
+/// ```
+/// use const_util::mem::wrap_slice;
+/// use core::num::Wrapping;
+/// const WRAPPED: &[Wrapping<u8>] = wrap_slice(&[1, 2]);
+/// assert_eq!(WRAPPED, [Wrapping(1), Wrapping(2)]);
+/// ```
+pub const fn wrap_slice<W: TransparentWrapper<I>, I>(inner: &[I]) -> &[W] {
+    const { assert_wrapper_layout::<W, I>() }
+    // SAFETY: `W` is a transparent wrapper around `I`, so `[W]` has the same layout as `[I]`
+    unsafe { &*ptr::slice_from_raw_parts(inner.as_ptr().cast(), inner.len()) }
+}
+/// Converts a slice of the wrapper into a slice of the inner type.
+///
+/// # Example
+/// ```
+/// use const_util::mem::peel_slice;
+/// use core::mem::ManuallyDrop;
+/// let strings = [ManuallyDrop::new(String::from("a"))];
+/// assert_eq!(peel_slice(&strings), ["a"]);
+/// # let [s] = strings;
+/// # ManuallyDrop::into_inner(s);
+/// ```
+pub const fn peel_slice<W: TransparentWrapper<I>, I>(wrapper: &[W]) -> &[I] {
+    const { assert_wrapper_layout::<W, I>() }
+    // SAFETY: `W` is a transparent wrapper around `I`, so `[W]` has the same layout as `[I]`
+    unsafe { &*ptr::slice_from_raw_parts(wrapper.as_ptr().cast(), wrapper.len()) }
+}
+
+/// A buffer for building an array one element at a time in `const` contexts.
+///
+/// The builder keeps track of how many elements have been initialized. If it is dropped before
+/// [`finish`](Self::finish) is called, for example because of a panic at runtime, the elements
+/// that were pushed are dropped.
+///
+/// Because the builder implements [`Drop`], it can never be dropped in `const` code, not even if
+/// `T` is [`Copy`]. In a `const fn`, every path must therefore consume the builder through
+/// [`finish`](Self::finish), and returning early or otherwise letting it go out of scope fails to
+/// compile.
+///
+/// # Example
+/// ```
+/// use const_util::mem::{man_drop_into_array, man_drop_ref, ArrayBuilder};
+/// use core::mem::ManuallyDrop;
+/// const fn interleave<T, const N: usize>(a: [T; N], b: [T; N]) -> [[T; 2]; N] {
+///     let a = man_drop_into_array(ManuallyDrop::new(a));
+///     let b = man_drop_into_array(ManuallyDrop::new(b));
+///     let mut out = ArrayBuilder::new();
+///     while !out.is_full() {
+///         let i = out.len();
+///         // SAFETY: Every element is read exactly once
+///         out.push(unsafe {
+///             [
+///                 core::ptr::read(man_drop_ref(&a[i])),
+///                 core::ptr::read(man_drop_ref(&b[i])),
+///             ]
+///         });
+///     }
+///     out.finish()
+/// }
+/// assert_eq!(
+///     interleave(["a", "b"].map(String::from), ["c", "d"].map(String::from)),
+///     [["a", "c"], ["b", "d"]],
+/// );
+/// ```
+/// ```compile_fail
+/// use const_util::mem::ArrayBuilder;
+/// const fn all_odd(values: [u8; 3]) -> Option<[u8; 3]> {
+///     let mut out = ArrayBuilder::new();
+///     let mut i = 0;
+///     while i < 3 {
+///         if values[i] % 2 == 0 {
+///             // The builder would be dropped here
+///             return None;
+///         }
+///         out.push(values[i]);
+///         i += 1;
+///     }
+///     Some(out.finish())
+/// }
+/// ```
+pub struct ArrayBuilder<T, const N: usize> {
+    arr: [MaybeUninit<T>; N],
+    len: usize,
+}
+impl<T, const N: usize> ArrayBuilder<T, N> {
+    /// Creates an empty builder.
+    pub const fn new() -> Self {
+        Self {
+            arr: uninit_into_array(MaybeUninit::uninit()),
+            len: 0,
+        }
+    }
+    /// Returns the number of elements that were pushed.
+    pub const fn len(&self) -> usize {
+        self.len
+    }
+    /// Returns whether no elements were pushed.
+    pub const fn is_empty(&self) -> bool {
+        self.len == 0
+    }
+    /// Returns whether `N` elements were pushed.
+    pub const fn is_full(&self) -> bool {
+        self.len == N
+    }
+    /// Returns the elements that were pushed.
+    pub const fn as_slice(&self) -> &[T] {
+        let init = self.arr.split_at(self.len).0;
+        // SAFETY: The first `len` elements are initialized
+        unsafe { &*(ptr::from_ref(init) as *const [T]) }
+    }
+    /// Appends an element.
+    ///
+    /// # Panics
+    /// If the builder is full.
+    #[track_caller]
+    pub const fn push(&mut self, value: T) {
+        assert!(
+            !self.is_full(),
+            "Attempted to push to a full `ArrayBuilder`"
+        );
+        self.arr[self.len] = MaybeUninit::new(value);
+        self.len += 1;
+    }
+    /// Returns the finished array.
+    ///
+    /// # Panics
+    /// If the builder is not full.
+    #[track_caller]
+    pub const fn finish(self) -> [T; N] {
+        assert!(
+            self.is_full(),
+            "Attempted to finish an `ArrayBuilder` that is not full"
+        );
+        let this = ManuallyDrop::new(self);
+        // SAFETY: The builder is forgotten and all elements are initialized
+        unsafe { uninit_from_array(ptr::read(&man_drop_ref(&this).arr)).assume_init() }
+    }
+}
+impl<T, const N: usize> Default for ArrayBuilder<T, N> {
+    fn default() -> Self {
+        Self::new()
+    }
+}
+impl<T: core::fmt::Debug, const N: usize> core::fmt::Debug for ArrayBuilder<T, N> {
+    fn fmt(&self, f: &mut core::fmt::Formatter<'_>) -> core::fmt::Result {
+        f.debug_list().entries(self.as_slice()).finish()
+    }
+}
+impl<T, const N: usize> Drop for ArrayBuilder<T, N> {
+    fn drop(&mut self) {
+        let init = ptr::from_mut(self.arr.split_at_mut(self.len).0) as *mut [T];
+        // SAFETY: The first `len` elements are initialized and not used afterwards
+        unsafe { ptr::drop_in_place(init) }
+    }
+}
+
+/// A builder for the layouts of `#[repr(C)]` structs, usable in `const` contexts.
+///
+/// Fields are added in order using [`field`](Self::field) and similar methods, which place each
+/// field at the next offset that is a multiple of its alignment. The offset of the last field can
+/// be retrieved using [`last_offset`](Self::last_offset). As with `#[repr(C)]` structs, the final
+/// size is obtained by padding the size to a multiple of the alignment using
+/// [`pad_to_align`](Self::pad_to_align).
+///
+/// The methods panic if the size of the layout would exceed [`isize::MAX`] when rounded up to its
+/// alignment, which is the same limit as that of [`Layout`](core::alloc::Layout).
+///
+/// # Example
+/// ```
+/// use const_util::mem::LayoutBuilder;
+/// use core::{alloc::Layout, mem::offset_of};
+/// #[repr(C)]
+/// struct Record<T> {
+///     tag: u8,
+///     value: T,
+///     data: [u16; 3],
+/// }
+/// const fn value_offset<T>() -> usize {
+///     LayoutBuilder::new().field::<u8>().field::<T>().last_offset()
+/// }
+/// const fn record_layout<T>() -> Layout {
+///     LayoutBuilder::new()
+///         .field::<u8>()
+///         .field::<T>()
+///         .array::<u16>(3)
+///         .pad_to_align()
+///         .layout()
+/// }
+/// assert_eq!(value_offset::<u64>(), offset_of!(Record<u64>, value));
+/// assert_eq!(record_layout::<u64>(), Layout::new::<Record<u64>>());
+/// assert_eq!(record_layout::<u8>(), Layout::new::<Record<u8>>());
+/// ```
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Hash)]
+pub struct LayoutBuilder {
+    size: usize,
+    align: usize,
+    last_offset: usize,
+}
+impl LayoutBuilder {
+    /// Creates the layout of a struct without fields, which has size `0` and alignment `1`.
+    pub const fn new() -> Self {
+        Self {
+            size: 0,
+            align: 1,
+            last_offset: 0,
+        }
+    }
+    /// Returns the size of the fields added so far, including padding between them, but not
+    /// trailing padding.
+    pub const fn size(&self) -> usize {
+        self.size
+    }
+    /// Returns the alignment, which is the greatest alignment of the fields added so far.
+    pub const fn align(&self) -> usize {
+        self.align
+    }
+    /// Returns the offset of the last field that was added, or `0` if there is none.
+    pub const fn last_offset(&self) -> usize {
+        self.last_offset
+    }
+    /// Adds a field of type `T`.
+    #[track_caller]
+    pub const fn field<T>(self) -> Self {
+        self.field_layout(size_of::<T>(), align_of::<T>())
+    }
+    /// Adds a field of type `[T; len]`.
+    #[track_caller]
+    pub const fn array<T>(self, len: usize) -> Self {
+        let Some(size) = size_of::<T>().checked_mul(len) else {
+            layout_overflow()
+        };
+        self.field_layout(size, align_of::<T>())
+    }
+    /// Adds a field with the given size and alignment.
+    ///
+    /// # Panics
+    /// If `align` is not a power of two or the size overflows.
+    #[track_caller]
+    pub const fn field_layout(self, size: usize, align: usize) -> Self {
+        assert!(align.is_power_of_two(), "alignment must be a power of two");
+        let offset = align_up(self.size, align);
+        let Some(end) = offset.checked_add(size) else {
+            layout_overflow()
+        };
+        let align = if align > self.align {
+            align
+        } else {
+            self.align
+        };
+        // Make sure that padding to the alignment cannot overflow `isize`
+        align_up(end, align);
+        Self {
+            size: end,
+            align,
+            last_offset: offset,
+        }
+    }
+    /// Adds a field with the layout built by `other`, including its trailing padding.
+    #[track_caller]
+    pub const fn nested(self, other: Self) -> Self {
+        let other = other.pad_to_align();
+        self.field_layout(other.size, other.align)
+    }
+    /// Rounds the size up to a multiple of the alignment, like the size of a `#[repr(C)]` struct.
+    pub const fn pad_to_align(self) -> Self {
+        Self {
+            size: align_up(self.size, self.align),
+            ..self
+        }
+    }
+    /// Returns the layout of an array of `n` elements of the struct built so far.
+    ///
+    /// The result is padded to its alignment and has no fields.
+    ///
+    /// # Example
+    /// ```
+    /// use const_util::mem::LayoutBuilder;
+    /// const ARR: LayoutBuilder = LayoutBuilder::new().field::<u32>().field::<u8>().repeat(3);
+    /// assert_eq!((ARR.size(), ARR.align()), (24, 4));
+    /// ```
+    #[track_caller]
+    pub const fn repeat(self, n: usize) -> Self {
+        let Some(size) = self.pad_to_align().size.checked_mul(n) else {
+            layout_overflow()
+        };
+        Self::new().field_layout(size, self.align)
+    }
+    /// Returns the size and alignment as a [`Layout`](core::alloc::Layout), without adding
+    /// trailing padding.
+    pub const fn layout(&self) -> core::alloc::Layout {
+        match core::alloc::Layout::from_size_align(self.size, self.align) {
+            Ok(layout) => layout,
+            // The alignment is a power of two and the padded size was checked when adding fields
+            Err(_) => unreachable!(),
+        }
+    }
+}
+impl Default for LayoutBuilder {
+    fn default() -> Self {
+        Self::new()
+    }
+}
+#[track_caller]
+const fn layout_overflow() -> ! {
+    panic!("layout size overflows `isize::MAX`")
+}
+/// Rounds `offset` up to a multiple of `align`, panicking if the result exceeds `isize::MAX`.
+#[track_caller]
+const fn align_up(offset: usize, align: usize) -> usize {
+    match offset.checked_add(align - 1) {
+        Some(end) if end & !(align - 1) <= isize::MAX as usize => end & !(align - 1),
+        _ => layout_overflow(),
+    }
+}
+
+/// Types for which the all-zero byte pattern is a valid value.
+///
+/// This is implemented for the primitive numeric types, [`bool`], [`char`], raw pointers,
+/// optional references and [`NonNull`]s, and for arrays and tuples of `Zeroable` types.
+///
+/// # Safety
+/// A value of `Self` consisting only of zero bytes must be valid.
+pub unsafe trait Zeroable: Sized {}
+
+/// "Plain old data" types, for which every byte pattern of the correct size is a valid value and
+/// which do not contain padding.
+///
+/// This is implemented for the primitive integer and floating point types and for arrays of `Pod`
+/// types. Tuples deliberately do not implement it, since their layout is unspecified and may
+/// contain padding. User structs can implement it using [`pod_struct!`](crate::pod_struct).
+///
+/// # Safety
+/// `Self` must not contain padding, pointers or interior mutability and every initialized byte
+/// pattern of size `size_of::<Self>()` must be a valid value of `Self`.
+///
+/// These requirements only have to hold if [`LAYOUT_CHECK`](Self::LAYOUT_CHECK) evaluates
+/// successfully. Code relying on them for a generic `T: Pod` must evaluate `T::LAYOUT_CHECK` in a
+/// `const` block first, as all functions in this crate do.
+pub unsafe trait Pod: Zeroable + Copy + 'static {
+    /// Fails to evaluate if `Self` does not satisfy the requirements of this trait.
+    ///
+    /// This allows [`pod_struct!`](crate::pod_struct) to support generic structs, whose padding
+    /// can only be checked once the type parameters are known. It defaults to `()`.
+    const LAYOUT_CHECK: () = ();
+}
+
+macro_rules! impl_pod {
+    ($($ty:ty),*) => {$(
+        // SAFETY: Primitive numeric type
+        unsafe impl Zeroable for $ty {}
+        // SAFETY: Primitive numeric type
+        unsafe impl Pod for $ty {}
+    )*};
+}
+impl_pod!(u8, u16, u32, u64, u128, usize, i8, i16, i32, i64, i128, isize, f32, f64);
+
+macro_rules! impl_zeroable {
+    ($([$($params:tt)*] $ty:ty;)*) => {$(
+        // SAFETY: Zero is a valid value
+        unsafe impl<$($params)*> Zeroable for $ty {}
+    )*};
+}
+impl_zeroable! {
+    [] bool;
+    [] char;
+    [T] *const T;
+    [T] *mut T;
+    [T] Option<&T>;
+    [T] Option<&mut T>;
+    [T] Option<NonNull<T>>;
+    [T] core::marker::PhantomData<T>;
+    [T] MaybeUninit<T>;
+    [T: Zeroable] ManuallyDrop<T>;
+    [T: Zeroable] core::num::Wrapping<T>;
+    [T: Zeroable, const N: usize] [T; N];
+}
+// SAFETY: An array of `Pod` types has no padding
+unsafe impl<T: Pod, const N: usize> Pod for [T; N] {
+    const LAYOUT_CHECK: () = T::LAYOUT_CHECK;
+}
+// SAFETY: A `#[repr(transparent)]` wrapper around a `Pod` type
+unsafe impl<T: Pod> Pod for core::num::Wrapping<T> {
+    const LAYOUT_CHECK: () = T::LAYOUT_CHECK;
+}
+
+macro_rules! impl_zeroable_tuples {
+    ([$($done:ident)*] []) => {
+        // SAFETY: A tuple of zeroable types is zeroable
+        unsafe impl<$($done: Zeroable),*> Zeroable for ($($done,)*) {}
+    };
+    ([$($done:ident)*] [$next:ident $($rest:ident)*]) => {
+        impl_zeroable_tuples!([$($done)*] []);
+        impl_zeroable_tuples!([$($done)* $next] [$($rest)*]);
+    };
+}
+impl_zeroable_tuples!([] [A B C D E F G H I J K L]);
+
+/// Implements [`Pod`] and [`Zeroable`] for a struct after verifying that it has no padding.
+///
+/// The macro accepts the struct definition and adds `#[repr(C)]` to it. The struct must implement
+/// [`Copy`] and all of its fields must implement [`Pod`]. Both tuple structs and structs with named
+/// fields are supported, as are type parameters without bounds, in which case the traits are
+/// implemented if the fields implement them. If the struct contains padding, a compile error is
+/// emitted. For generic structs, this happens when a function using [`Pod`] is monomorphized with
+/// a padded instantiation, since the padding depends on the type parameters.
+///
+/// # Example
+/// ```
+/// use const_util::{mem::{as_bytes, read_pod}, pod_struct};
+/// pod_struct! {
+///     #[derive(Debug, Clone, Copy, PartialEq)]
+///     pub struct Header {
+///         pub magic: [u8; 4],
+///         pub len: u32,
+///     }
+/// }
+/// const BYTES: &[u8] = b"xxABCD\x02\0\0\0";
+/// const HEADER: Header = match read_pod(BYTES, 2) {
+///     Some(header) => header,
+///     None => panic!("Truncated header"),
+/// };
+/// assert_eq!(HEADER.magic, *b"ABCD");
+/// assert_eq!(as_bytes(&HEADER), &BYTES[2..]);
+///
+/// pod_struct! {
+///     #[derive(Clone, Copy)]
+///     pub struct Pair<A, B>(pub A, pub B);
+/// }
+/// assert_eq!(as_bytes(&Pair(1u8, 2u8)), [1, 2]);
+/// ```
+///
+/// Structs with padding are rejected:
+/// ```compile_fail
+/// const_util::pod_struct! {
+///     #[derive(Clone, Copy)]
+///     struct Padded(u8, u32);
+/// }
+/// ```
+/// ```compile_fail
+/// const_util::pod_struct! {
+///     #[derive(Clone, Copy)]
+///     struct Pair<A, B>(A, B);
+/// }
+/// const_util::mem::as_bytes(&Pair(1u8, 2u32));
+/// ```
+#[macro_export]
+macro_rules! pod_struct {
+    (
+        $(#[$meta:meta])*
+        $vis:vis struct $name:ident $(<$($param:ident),* $(,)?>)? {
+            $($(#[$fmeta:meta])* $fvis:vis $field:ident: $fty:ty),* $(,)?
+        }
+    ) => {
+        $(#[$meta])*
+        #[repr(C)]
+        $vis struct $name $(<$($param),*>)? {
+            $($(#[$fmeta])* $fvis $field: $fty),*
+        }
+        $crate::__pod_struct_impls!($name [$($($param)*)?] $($fty)*);
+    };
+    (
+        $(#[$meta:meta])*
+        $vis:vis struct $name:ident $(<$($param:ident),* $(,)?>)?
+            ($($(#[$fmeta:meta])* $fvis:vis $fty:ty),* $(,)?);
+    ) => {
+        $(#[$meta])*
+        #[repr(C)]
+        $vis struct $name $(<$($param),*>)? ($($(#[$fmeta])* $fvis $fty),*);
+        $crate::__pod_struct_impls!($name [$($($param)*)?] $($fty)*);
+    };
+}
+#[doc(hidden)]
+#[macro_export]
+macro_rules! __pod_struct_impls {
+    ($name:ident [] $($fty:ty)*) => {
+        $crate::__pod_struct_impls!(@impls $name [] $($fty)*);
+        // Check non-generic structs where they are declared
+        const _: () = <$name as $crate::mem::Pod>::LAYOUT_CHECK;
+    };
+    ($name:ident [$($param:ident)+] $($fty:ty)*) => {
+        $crate::__pod_struct_impls!(@impls $name [$($param)+] $($fty)*);
+    };
+    (@impls $name:ident [$($param:ident)*] $($fty:ty)*) => {
+        // SAFETY: The struct is `#[repr(C)]` and its fields are zeroable
+        unsafe impl<$($param),*> $crate::mem::Zeroable for $name<$($param),*>
+        where
+            $($fty: $crate::mem::Zeroable,)*
+        {
+        }
+        // SAFETY: The struct is `#[repr(C)]` and its fields are `Pod`. `LAYOUT_CHECK` fails if it
+        // contains padding.
+        unsafe impl<$($param),*> $crate::mem::Pod for $name<$($param),*>
+        where
+            $name<$($param),*>: $crate::__mac::core::marker::Copy + 'static,
+            $($fty: $crate::mem::Pod,)*
+        {
+            const LAYOUT_CHECK: () = {
+                $(let () = <$fty as $crate::mem::Pod>::LAYOUT_CHECK;)*
+                assert!(
+                    $crate::__mac::core::mem::size_of::<Self>()
+                        == 0 $(+ $crate::__mac::core::mem::size_of::<$fty>())*,
+                    "struct contains padding",
+                );
+            };
+        }
+    };
+}
+
+/// Returns a value consisting of zero bytes.
+///
+/// # Example
+/// ```
+/// use const_util::mem::zeroed;
+/// const ZERO: (u32, [bool; 2], *const u8) = zeroed();
+/// assert_eq!(ZERO, (0, [false; 2], core::ptr::null()));
+/// ```
+pub const fn zeroed<T: Zeroable>() -> T {
+    // SAFETY: The all-zero byte pattern is a valid `T`
+    unsafe { MaybeUninit::zeroed().assume_init() }
+}
+
+/// Returns the bytes of a value.
+///
+/// # Example
+/// ```
+/// use const_util::mem::as_bytes;
+/// assert_eq!(as_bytes(&0x0102u16.to_be()), [1, 2]);
+/// ```
+pub const fn as_bytes<T: Pod>(value: &T) -> &[u8] {
+    const { T::LAYOUT_CHECK }
+    // SAFETY: `T` has no padding, so all of its bytes are initialized
+    unsafe { core::slice::from_raw_parts(ptr::from_ref(value).cast(), size_of::<T>()) }
+}
+
+/// Reads a value from its bytes, if the length of `bytes` is the size of `T`.
+///
+/// # Example
+/// ```
+/// use const_util::mem::from_bytes;
+/// assert_eq!(from_bytes::<[u8; 2]>(b"ab"), Some(*b"ab"));
+/// assert_eq!(from_bytes::<u16>(b"abc"), None);
+/// ```
+pub const fn from_bytes<T: Pod>(bytes: &[u8]) -> Option<T> {
+    const { T::LAYOUT_CHECK }
+    if bytes.len() != size_of::<T>() {
+        return None;
+    }
+    // SAFETY: The bytes are initialized and any bytes are a valid `T`
+    Some(unsafe { bytes.as_ptr().cast::<T>().read_unaligned() })
+}
+
+/// Reads a value from `bytes` at the byte offset `offset`, if it is in bounds.
+///
+/// This uses [`slice_get`](crate::slice::slice_get) to get the bytes of the value.
+///
+/// # Example
+/// ```
+/// use const_util::mem::read_pod;
+/// assert_eq!(read_pod::<[u8; 2]>(b"abc", 1), Some(*b"bc"));
+/// assert_eq!(read_pod::<[u8; 2]>(b"abc", 2), None);
+/// assert_eq!(read_pod::<[u8; 2]>(b"abc", usize::MAX), None);
+/// ```
+pub const fn read_pod<T: Pod>(bytes: &[u8], offset: usize) -> Option<T> {
+    let Some(end) = offset.checked_add(size_of::<T>()) else {
+        return None;
+    };
+    match crate::slice::slice_get(bytes, offset..end) {
+        Some(bytes) => from_bytes(bytes),
+        None => None,
+    }
+}
+
+/// Reinterprets a slice of `A` as a slice of `B`, if its size in bytes is a multiple of the size
+/// of `B`.
+///
+/// Since the alignment of pointers cannot be checked in `const` contexts, this function fails to
+/// compile if `B` has a greater alignment than `A` or if `B` is zero-sized.
+///
+/// # Example
+/// ```
+/// use const_util::mem::cast_slice;
+/// const WORDS: &[u16] = &[1, 2];
+/// assert_eq!(cast_slice::<_, u8>(WORDS).unwrap().len(), 4);
+/// assert_eq!(cast_slice::<_, [u16; 2]>(WORDS), Some(&[[1, 2]][..]));
+/// assert_eq!(cast_slice::<_, [u16; 3]>(WORDS), None);
+/// ```
+/// ```compile_fail
+/// const_util::mem::cast_slice::<u8, u16>(&[1, 2]);
+/// ```
+pub const fn cast_slice<A: Pod, B: Pod>(slice: &[A]) -> Option<&[B]> {
+    const {
+        let () = A::LAYOUT_CHECK;
+        let () = B::LAYOUT_CHECK;
+        assert!(
+            size_of::<B>() != 0,
+            "cannot cast to a slice of a zero-sized type"
+        );
+        if align_of::<B>() > align_of::<A>() {
+            layout_mismatch::<A, B>("cannot cast to a slice of a type with a greater alignment")
+        }
+    }
+    let size = size_of::<A>() * slice.len();
+    if size % size_of::<B>() != 0 {
+        return None;
+    }
+    // SAFETY: The slice is sufficiently aligned for `B` and has the same size in bytes. `A` has
+    // no padding and any bytes are a valid `B`.
+    Some(unsafe { core::slice::from_raw_parts(slice.as_ptr().cast(), size / size_of::<B>()) })
+}
+
 mod hidden {
-    /// # Safety
-    /// `Self` must be `&Referee` and `MUTABLE = false` or `&mut Referee` and `MUTABLE = true`
-    pub unsafe trait Reference: Into<core::ptr::NonNull<Self::Referee>> {
-        type Referee: ?Sized;
-        const MUTABLE: bool;
-    }
-    // SAFETY: trivial
-    unsafe impl<T: ?Sized> Reference for &T {
-        type Referee = T;
-        const MUTABLE: bool = false;
-    }
-    // SAFETY: trivial
-    unsafe impl<T: ?Sized> Reference for &mut T {
-        type Referee = T;
-        const MUTABLE: bool = true;
+    pub trait Sealed {}
+}
+/// The primitive integer types, which can be read from and written to byte buffers using
+/// [`read_le`], [`write_le`] and related functions.
+///
+/// This trait is sealed and cannot be implemented outside of this crate.
+pub trait Integer: Pod + hidden::Sealed {}
+macro_rules! impl_integer {
+    ($($ty:ty),*) => {$(
+        impl hidden::Sealed for $ty {}
+        impl Integer for $ty {}
+    )*};
+}
+impl_integer!(u8, u16, u32, u64, u128, usize, i8, i16, i32, i64, i128, isize);
+
+const fn int_range<T: Integer>(offset: usize) -> Result<core::ops::Range<usize>, SliceIndexError> {
+    match offset.checked_add(size_of::<T>()) {
+        Some(end) => Ok(offset..end),
+        None => Err(SliceIndexError::Overflow),
+    }
+}
+/// Converts bytes of the given endianness into an integer. `bytes` must have the size of `T`.
+const fn int_from_bytes<T: Integer>(bytes: &[u8], little_endian: bool) -> T {
+    let mut out = MaybeUninit::<T>::uninit();
+    let out_ptr = out.as_mut_ptr().cast::<u8>();
+    let reverse = little_endian != cfg!(target_endian = "little");
+    let mut i = 0;
+    while i < size_of::<T>() {
+        let byte = bytes[if reverse { size_of::<T>() - 1 - i } else { i }];
+        // SAFETY: `i` is in bounds of `out`
+        unsafe { out_ptr.add(i).write(byte) };
+        i += 1;
+    }
+    // SAFETY: All bytes were written and any bytes are a valid integer
+    unsafe { out.assume_init() }
+}
+/// Writes the bytes of an integer in the given endianness. `out` must have the size of `T`.
+const fn int_to_bytes<T: Integer>(value: T, out: &mut [u8], little_endian: bool) {
+    let bytes = as_bytes(&value);
+    let reverse = little_endian != cfg!(target_endian = "little");
+    let mut i = 0;
+    while i < size_of::<T>() {
+        out[i] = bytes[if reverse { size_of::<T>() - 1 - i } else { i }];
+        i += 1;
     }
 }
+macro_rules! int_accessors {
+    ($(
+        $endian:literal, $short:literal, $little:literal,
+        $read:ident, $checked_read:ident, $write:ident, $checked_write:ident;
+    )*) => {$(
+        #[doc = concat!("Reads a ", $endian, " integer from `bytes` at the byte offset `offset`.")]
+        ///
+        /// # Panics
+        /// If the integer is not in bounds of `bytes`, with the same message as
+        /// [`slice_index`](crate::slice::slice_index).
+        ///
+        /// # Example
+        /// ```
+        #[doc = concat!("use const_util::mem::", stringify!($read), ";")]
+        /// const BYTES: &[u8] = &[0, 1, 2];
+        #[doc = concat!("const N: u16 = ", stringify!($read), "(BYTES, 1);")]
+        #[doc = concat!("assert_eq!(N, u16::from_", $short, "_bytes([1, 2]));")]
+        /// ```
+        #[track_caller]
+        pub const fn $read<T: Integer>(bytes: &[u8], offset: usize) -> T {
+            match int_range::<T>(offset) {
+                Ok(range) => match crate::slice::try_slice_index(bytes, range) {
+                    Ok(bytes) => int_from_bytes(bytes, $little),
+                    Err(err) => err.panic(),
+                },
+                Err(err) => err.panic(),
+            }
+        }
+        #[doc = concat!("Reads a ", $endian, " integer from `bytes` at the byte offset `offset`, if it is in bounds.")]
+        ///
+        /// # Example
+        /// ```
+        #[doc = concat!("use const_util::mem::", stringify!($checked_read), ";")]
+        #[doc = concat!("assert_eq!(", stringify!($checked_read), "::<u8>(&[1, 2], 1), Some(2));")]
+        #[doc = concat!("assert_eq!(", stringify!($checked_read), "::<u16>(&[1, 2], 1), None);")]
+        /// ```
+        pub const fn $checked_read<T: Integer>(bytes: &[u8], offset: usize) -> Option<T> {
+            let Ok(range) = int_range::<T>(offset) else {
+                return None;
+            };
+            match crate::slice::slice_get(bytes, range) {
+                Some(bytes) => Some(int_from_bytes(bytes, $little)),
+                None => None,
+            }
+        }
+        #[doc = concat!("Writes an integer to `bytes` at the byte offset `offset` in ", $endian, " byte order.")]
+        ///
+        /// # Panics
+        /// If the integer is not in bounds of `bytes`, with the same message as
+        /// [`slice_index_mut`](crate::slice::slice_index_mut).
+        ///
+        /// # Example
+        /// ```
+        #[doc = concat!("use const_util::mem::", stringify!($write), ";")]
+        /// const BYTES: [u8; 3] = {
+        ///     let mut bytes = [0; 3];
+        #[doc = concat!("    ", stringify!($write), "(&mut bytes, 1, 0x0102u16);")]
+        ///     bytes
+        /// };
+        #[doc = concat!("assert_eq!(BYTES[1..], 0x0102u16.to_", $short, "_bytes());")]
+        /// ```
+        #[track_caller]
+        pub const fn $write<T: Integer>(bytes: &mut [u8], offset: usize, value: T) {
+            match int_range::<T>(offset) {
+                Ok(range) => match crate::slice::try_slice_index_mut(bytes, range) {
+                    Ok(bytes) => int_to_bytes(value, bytes, $little),
+                    Err(err) => err.panic(),
+                },
+                Err(err) => err.panic(),
+            }
+        }
+        #[doc = concat!("Writes an integer to `bytes` at the byte offset `offset` in ", $endian, " byte order, if it is in bounds.")]
+        ///
+        /// Returns `None` without modifying `bytes` if the integer is out of bounds.
+        ///
+        /// # Example
+        /// ```
+        #[doc = concat!("use const_util::mem::", stringify!($checked_write), ";")]
+        /// let mut bytes = [0; 2];
+        #[doc = concat!("assert_eq!(", stringify!($checked_write), "(&mut bytes, 1, 1u8), Some(()));")]
+        #[doc = concat!("assert_eq!(", stringify!($checked_write), "(&mut bytes, 1, 1u16), None);")]
+        /// assert_eq!(bytes, [0, 1]);
+        /// ```
+        pub const fn $checked_write<T: Integer>(
+            bytes: &mut [u8],
+            offset: usize,
+            value: T,
+        ) -> Option<()> {
+            let Ok(range) = int_range::<T>(offset) else {
+                return None;
+            };
+            match crate::slice::slice_get_mut(bytes, range) {
+                Some(bytes) => Some(int_to_bytes(value, bytes, $little)),
+                None => None,
+            }
+        }
+    )*};
+}
+int_accessors! {
+    "little-endian", "le", true, read_le, checked_read_le, write_le, checked_write_le;
+    "big-endian", "be", false, read_be, checked_read_be, write_be, checked_write_be;
+}
+
+#[test]
+fn test_array_builder_drop() {
+    use std::rc::Rc;
+    let rc = Rc::new(());
+    let res = std::panic::catch_unwind(std::panic::AssertUnwindSafe(|| {
+        let mut builder = ArrayBuilder::<_, 3>::new();
+        builder.push(rc.clone());
+        builder.push(rc.clone());
+        assert_eq!(Rc::strong_count(&rc), 3);
+        builder.finish()
+    }));
+    assert!(res.is_err());
+    assert_eq!(Rc::strong_count(&rc), 1);
+
+    let mut builder = ArrayBuilder::<_, 1>::new();
+    builder.push(rc.clone());
+    let arr = builder.finish();
+    assert_eq!(Rc::strong_count(&rc), 2);
+    drop(arr);
+    assert_eq!(Rc::strong_count(&rc), 1);
+}
+
+#[test]
+fn test_int_accessors() {
+    let bytes: [u8; 20] = core::array::from_fn(|i| i as u8 * 13);
+    assert_eq!(
+        read_le::<i128>(&bytes, 3),
+        i128::from_le_bytes(bytes[3..19].try_into().unwrap())
+    );
+    assert_eq!(
+        read_be::<u32>(&bytes, 16),
+        u32::from_be_bytes(bytes[16..].try_into().unwrap())
+    );
+    assert_eq!(checked_read_le::<u32>(&bytes, 17), None);
+    assert_eq!(checked_read_be::<u8>(&bytes, usize::MAX), None);
+
+    let mut out = [0; 20];
+    write_be(&mut out, 2, -2i64);
+    assert_eq!(out[2..10], (-2i64).to_be_bytes());
+    write_le(&mut out, 12, u64::MAX - 1);
+    assert_eq!(out[12..], (u64::MAX - 1).to_le_bytes());
+    assert_eq!(checked_write_le(&mut out, usize::MAX, 0u16), None);
+}
@@ -0,0 +1,174 @@
+//! Functions for dealing with [`Option`]s with generics or drop glue
+//!
+//! Functions that need to discard a value wrap it in a [`ManuallyDrop`], meaning that its
+//! destructor is not run. This is the only way to discard a generic value in a `const` context.
+//! Each function that leaks a value this way says so in its documentation.
+
+use core::mem::ManuallyDrop;
+
+/// Discards `value` without running its destructor.
+const fn leak<T>(value: T) {
+    let _ = ManuallyDrop::new(value);
+}
+
+/// Const version of [`Option::expect`].
+///
+/// Can also be used in combination with [`Option::is_some`] to match the option.
+///
+/// # Example
+/// ```
+/// use const_util::option::expect_some;
+/// const fn pair_to_arr<T>(pair: Option<(T, T)>) -> Option<[T; 2]> {
+///     if pair.is_some() {
+///         const_util::destruct_tuple! { a, b in expect_some(pair, "Unreachable") }
+///         Some([a, b])
+///     } else {
+///         core::mem::forget(pair);
+///         None
+///     }
+/// }
+/// assert_eq!(
+///     pair_to_arr(Some((String::from("a"), String::new()))),
+///     Some([String::from("a"), String::new()]),
+/// );
+/// ```
+#[track_caller]
+pub const fn expect_some<T>(opt: Option<T>, message: &str) -> T {
+    crate::expect_variant!(opt, Some, message)
+}
+/// Const version of [`Option::unwrap`].
+///
+/// Can also be used in combination with [`Option::is_some`] to match the option.
+///
+/// # Example
+/// ```
+/// use const_util::option::unwrap_some;
+/// const fn or_empty(opt: Option<String>) -> String {
+///     if opt.is_some() {
+///         unwrap_some(opt)
+///     } else {
+///         core::mem::forget(opt);
+///         String::new()
+///     }
+/// }
+/// assert_eq!(or_empty(Some(String::from("a"))), "a");
+/// ```
+#[track_caller]
+pub const fn unwrap_some<T>(opt: Option<T>) -> T {
+    expect_some(opt, "Attempted to call `unwrap_some` on a `None` value")
+}
+/// Asserts that the option is `None`.
+///
+/// If the option is `Some`, the payload is leaked before panicking.
+///
+/// # Example
+/// ```
+/// use const_util::option::expect_none;
+/// const fn only_one<T>(first: T, second: Option<T>) -> T {
+///     expect_none(second, "More than one value");
+///     first
+/// }
+/// assert_eq!(only_one(String::from("a"), None), "a");
+/// ```
+#[track_caller]
+pub const fn expect_none<T>(opt: Option<T>, message: &str) {
+    let opt = ManuallyDrop::new(opt);
+    if crate::mem::man_drop_ref(&opt).is_some() {
+        panic!("{}", message)
+    }
+}
+
+/// Same as [`Option::take`], which is `const` as of 1.83. Provided for completeness.
+///
+/// # Example
+/// ```
+/// use const_util::option::take;
+/// let mut opt = Some(String::from("a"));
+/// assert_eq!(take(&mut opt).as_deref(), Some("a"));
+/// assert_eq!(opt, None);
+/// ```
+pub const fn take<T>(opt: &mut Option<T>) -> Option<T> {
+    opt.take()
+}
+/// Same as [`Option::replace`], which is `const` as of 1.83. Provided for completeness.
+///
+/// # Example
+/// ```
+/// use const_util::option::replace;
+/// let mut opt = Some(String::from("a"));
+/// assert_eq!(replace(&mut opt, String::from("b")).as_deref(), Some("a"));
+/// assert_eq!(opt.as_deref(), Some("b"));
+/// ```
+pub const fn replace<T>(opt: &mut Option<T>, value: T) -> Option<T> {
+    opt.replace(value)
+}
+/// Const version of [`Option::insert`].
+///
+/// Unlike [`Option::insert`], which drops the previous value, this function leaks it, meaning that
+/// its destructor is not run. Use [`replace`] to retrieve the previous value if it has to be
+/// dropped.
+///
+/// # Example
+/// ```
+/// use const_util::option::insert;
+/// let mut opt = None;
+/// *insert(&mut opt, 1) += 1;
+/// assert_eq!(opt, Some(2));
+/// ```
+pub const fn insert<T>(opt: &mut Option<T>, value: T) -> &mut T {
+    leak(replace(opt, value));
+    match opt {
+        Some(value) => value,
+        None => unreachable!(),
+    }
+}
+/// Const version of [`Option::get_or_insert`].
+///
+/// Unlike [`Option::get_or_insert`], which drops `value` if the option is `Some`, this function
+/// leaks it, meaning that its destructor is not run. Check [`Option::is_none`] first to only create
+/// the value when it is inserted if it has to be dropped.
+///
+/// # Example
+/// ```
+/// use const_util::option::get_or_insert;
+/// let mut opt = Some(1);
+/// *get_or_insert(&mut opt, 5) += 1;
+/// assert_eq!(opt, Some(2));
+/// ```
+pub const fn get_or_insert<T>(opt: &mut Option<T>, value: T) -> &mut T {
+    if opt.is_none() {
+        // `opt` is `None`, so nothing is leaked here
+        leak(replace(opt, value));
+    } else {
+        leak(value);
+    }
+    match opt {
+        Some(value) => value,
+        None => unreachable!(),
+    }
+}
+/// Const version of [`Option::unwrap_or`].
+///
+/// Unlike [`Option::unwrap_or`], which drops `default` if the option is `Some`, this function
+/// leaks it, meaning that its destructor is not run. Check [`Option::is_some`] first and use
+/// [`unwrap_some`] to only create the default when it is used if it has to be dropped.
+///
+/// # Example
+/// ```
+/// use const_util::option::unwrap_or;
+/// const fn or_empty(opt: Option<String>) -> String {
+///     unwrap_or(opt, String::new())
+/// }
+/// assert_eq!(or_empty(Some(String::from("a"))), "a");
+/// assert_eq!(or_empty(None), "");
+/// ```
+pub const fn unwrap_or<T>(opt: Option<T>, default: T) -> T {
+    if opt.is_some() {
+        leak(default);
+        unwrap_some(opt)
+    } else {
+        // `opt` is `None`, so nothing is leaked here
+        leak(opt);
+        default
+    }
+}
@@ -0,0 +1,301 @@
+//! Const versions of standard library functions that are only `const` on newer compilers
+//!
+//! Each function calls the standard library version when the compiler supports calling it in
+//! `const` contexts and falls back to an equivalent implementation otherwise, so that crates using
+//! them can keep the MSRV of this crate. The version in which each function became `const` is
+//! listed in its documentation.
+//!
+//! [`NonNull::as_mut`](core::ptr::NonNull::as_mut), [`Option::as_mut`] and the `to_bits` and
+//! `from_bits` methods of the floating point types are already `const` on the MSRV and can be
+//! called directly.
+
+use core::{ptr, str::Utf8Error};
+
+/// Copies all elements from `src` into `dst`.
+///
+/// Const version of [`<[T]>::copy_from_slice`](slice::copy_from_slice), which is `const` since
+/// Rust 1.87.
+///
+/// # Panics
+/// If the slices have different lengths.
+///
+/// # Example
+/// ```
+/// use const_util::polyfill::slice_copy_from_slice;
+/// const ARR: [u8; 4] = {
+///     let mut arr = [0; 4];
+///     slice_copy_from_slice(arr.split_at_mut(2).1, &[1, 2]);
+///     arr
+/// };
+/// assert_eq!(ARR, [0, 0, 1, 2]);
+/// ```
+#[track_caller]
+pub const fn slice_copy_from_slice<T: Copy>(dst: &mut [T], src: &[T]) {
+    #[rustversion::since(1.87)]
+    #[allow(clippy::incompatible_msrv)]
+    #[track_caller]
+    const fn imp<T: Copy>(dst: &mut [T], src: &[T]) {
+        dst.copy_from_slice(src);
+    }
+    #[rustversion::before(1.87)]
+    #[track_caller]
+    const fn imp<T: Copy>(dst: &mut [T], src: &[T]) {
+        assert!(
+            src.len() == dst.len(),
+            "source slice length does not match destination slice length"
+        );
+        // SAFETY: T: Copy. This is literally how copy_from_slice is implemented.
+        unsafe {
+            ptr::copy_nonoverlapping(src.as_ptr(), dst.as_mut_ptr(), src.len());
+        }
+    }
+    imp(dst, src)
+}
+
+/// Swaps the values at two mutable locations.
+///
+/// Const version of [`core::mem::swap`], which is `const` since Rust 1.85.
+///
+/// # Example
+/// ```
+/// use const_util::polyfill::mem_swap;
+/// const fn swap_pair<T>(pair: &mut (T, T)) {
+///     let (a, b) = pair;
+///     mem_swap(a, b);
+/// }
+/// let mut pair = (String::from("a"), String::from("b"));
+/// swap_pair(&mut pair);
+/// assert_eq!(pair, ("b".into(), "a".into()));
+/// ```
+pub const fn mem_swap<T>(x: &mut T, y: &mut T) {
+    #[rustversion::since(1.85)]
+    #[allow(clippy::incompatible_msrv)]
+    const fn imp<T>(x: &mut T, y: &mut T) {
+        core::mem::swap(x, y)
+    }
+    #[rustversion::before(1.85)]
+    const fn imp<T>(x: &mut T, y: &mut T) {
+        // SAFETY: Mutable references are valid for reads and writes and cannot overlap
+        unsafe { ptr_swap(x, y) }
+    }
+    imp(x, y)
+}
+
+/// Swaps the values at two mutable locations of the same type, without deinitializing either.
+///
+/// Const version of [`core::ptr::swap`], which is `const` since Rust 1.85.
+///
+/// # Safety
+/// See [`core::ptr::swap`]. In particular, the locations may overlap.
+///
+/// # Example
+/// ```
+/// use const_util::polyfill::ptr_swap;
+/// let mut arr = [1, 2, 3, 4];
+/// let ptr = arr.as_mut_ptr().cast::<[i32; 2]>();
+/// // SAFETY: Both pointers are valid and aligned
+/// unsafe { ptr_swap(ptr, ptr.cast::<i32>().add(2).cast()) };
+/// assert_eq!(arr, [3, 4, 1, 2]);
+/// ```
+pub const unsafe fn ptr_swap<T>(x: *mut T, y: *mut T) {
+    #[rustversion::since(1.85)]
+    #[allow(clippy::incompatible_msrv)]
+    const unsafe fn imp<T>(x: *mut T, y: *mut T) {
+        // SAFETY: Guaranteed by the caller
+        unsafe { ptr::swap(x, y) }
+    }
+    #[rustversion::before(1.85)]
+    const unsafe fn imp<T>(x: *mut T, y: *mut T) {
+        let mut tmp = core::mem::MaybeUninit::<T>::uninit();
+        // SAFETY: Guaranteed by the caller. `tmp` is a separate allocation and `x` is read into it
+        // before it is overwritten, so the locations may overlap.
+        unsafe {
+            ptr::copy_nonoverlapping(x, tmp.as_mut_ptr(), 1);
+            ptr::copy(y, x, 1);
+            ptr::copy_nonoverlapping(tmp.as_ptr(), y, 1);
+        }
+    }
+    // SAFETY: Guaranteed by the caller
+    unsafe { imp(x, y) }
+}
+
+/// Swaps two elements in a slice.
+///
+/// Const version of [`<[T]>::swap`](slice::swap), which is `const` since Rust 1.85.
+///
+/// # Panics
+/// If `a` or `b` are out of bounds.
+///
+/// # Example
+/// ```
+/// use const_util::polyfill::slice_swap;
+/// const ARR: [u8; 3] = {
+///     let mut arr = [1, 2, 3];
+///     slice_swap(&mut arr, 0, 2);
+///     arr
+/// };
+/// assert_eq!(ARR, [3, 2, 1]);
+/// ```
+#[track_caller]
+pub const fn slice_swap<T>(slice: &mut [T], a: usize, b: usize) {
+    #[rustversion::since(1.85)]
+    #[allow(clippy::incompatible_msrv)]
+    #[track_caller]
+    const fn imp<T>(slice: &mut [T], a: usize, b: usize) {
+        slice.swap(a, b)
+    }
+    #[rustversion::before(1.85)]
+    #[track_caller]
+    const fn imp<T>(slice: &mut [T], a: usize, b: usize) {
+        let _ = (&slice[a], &slice[b]);
+        let ptr = slice.as_mut_ptr();
+        // SAFETY: `a` and `b` were checked to be in bounds
+        unsafe { ptr_swap(ptr.add(a), ptr.add(b)) }
+    }
+    imp(slice, a, b)
+}
+
+/// Reverses the order of the elements in a slice.
+///
+/// Const version of [`<[T]>::reverse`](slice::reverse), which is `const` since Rust 1.90.
+///
+/// # Example
+/// ```
+/// use const_util::polyfill::slice_reverse;
+/// const ARR: [u8; 5] = {
+///     let mut arr = [1, 2, 3, 4, 5];
+///     slice_reverse(&mut arr);
+///     arr
+/// };
+/// assert_eq!(ARR, [5, 4, 3, 2, 1]);
+/// ```
+pub const fn slice_reverse<T>(slice: &mut [T]) {
+    #[rustversion::since(1.90)]
+    #[allow(clippy::incompatible_msrv)]
+    const fn imp<T>(slice: &mut [T]) {
+        slice.reverse()
+    }
+    #[rustversion::before(1.90)]
+    const fn imp<T>(slice: &mut [T]) {
+        let len = slice.len();
+        let ptr = slice.as_mut_ptr();
+        let mut i = 0;
+        while i < len / 2 {
+            // SAFETY: `i < len - 1 - i < len`
+            unsafe { ptr_swap(ptr.add(i), ptr.add(len - 1 - i)) };
+            i += 1;
+        }
+    }
+    imp(slice)
+}
+
+/// Takes a slice of arrays and flattens it into a slice of their elements.
+///
+/// Const version of [`<[[T; N]]>::as_flattened`](slice::as_flattened), which is `const` since
+/// Rust 1.87.
+///
+/// # Panics
+/// If the length of the resulting slice would overflow a `usize`, which is only possible for
+/// zero-sized `T`.
+///
+/// # Example
+/// ```
+/// use const_util::polyfill::slice_as_flattened;
+/// const FLAT: &[u8] = slice_as_flattened(&[[1, 2], [3, 4]]);
+/// assert_eq!(FLAT, [1, 2, 3, 4]);
+/// ```
+#[track_caller]
+pub const fn slice_as_flattened<T, const N: usize>(slice: &[[T; N]]) -> &[T] {
+    #[rustversion::since(1.87)]
+    #[allow(clippy::incompatible_msrv)]
+    #[track_caller]
+    const fn imp<T, const N: usize>(slice: &[[T; N]]) -> &[T] {
+        slice.as_flattened()
+    }
+    #[rustversion::before(1.87)]
+    #[track_caller]
+    const fn imp<T, const N: usize>(slice: &[[T; N]]) -> &[T] {
+        let len = flattened_len::<T, N>(slice.len());
+        // SAFETY: `[[T; N]]` has the same layout as `[T]` of `len` elements
+        unsafe { core::slice::from_raw_parts(slice.as_ptr().cast(), len) }
+    }
+    imp(slice)
+}
+
+/// Takes a mutable slice of arrays and flattens it into a mutable slice of their elements.
+///
+/// Const version of [`<[[T; N]]>::as_flattened_mut`](slice::as_flattened_mut), which is `const`
+/// since Rust 1.87.
+///
+/// # Panics
+/// If the length of the resulting slice would overflow a `usize`, which is only possible for
+/// zero-sized `T`.
+///
+/// # Example
+/// ```
+/// use const_util::polyfill::{slice_as_flattened_mut, slice_reverse};
+/// const ARR: [[u8; 2]; 2] = {
+///     let mut arr = [[1, 2], [3, 4]];
+///     slice_reverse(slice_as_flattened_mut(&mut arr));
+///     arr
+/// };
+/// assert_eq!(ARR, [[4, 3], [2, 1]]);
+/// ```
+#[track_caller]
+pub const fn slice_as_flattened_mut<T, const N: usize>(slice: &mut [[T; N]]) -> &mut [T] {
+    #[rustversion::since(1.87)]
+    #[allow(clippy::incompatible_msrv)]
+    #[track_caller]
+    const fn imp<T, const N: usize>(slice: &mut [[T; N]]) -> &mut [T] {
+        slice.as_flattened_mut()
+    }
+    #[rustversion::before(1.87)]
+    #[track_caller]
+    const fn imp<T, const N: usize>(slice: &mut [[T; N]]) -> &mut [T] {
+        let len = flattened_len::<T, N>(slice.len());
+        // SAFETY: `[[T; N]]` has the same layout as `[T]` of `len` elements
+        unsafe { core::slice::from_raw_parts_mut(slice.as_mut_ptr().cast(), len) }
+    }
+    imp(slice)
+}
+
+#[rustversion::before(1.87)]
+#[track_caller]
+const fn flattened_len<T, const N: usize>(len: usize) -> usize {
+    if size_of::<T>() == 0 {
+        len.checked_mul(N).expect("slice len overflow")
+    } else {
+        // Cannot overflow, since the slice of arrays fits into memory
+        len * N
+    }
+}
+
+/// Converts a mutable slice of bytes into a mutable string slice, if it is valid UTF-8.
+///
+/// Const version of [`core::str::from_utf8_mut`], which is `const` since Rust 1.87.
+///
+/// # Example
+/// ```
+/// use const_util::polyfill::str_from_utf8_mut;
+/// let mut bytes = *b"hello";
+/// let s = str_from_utf8_mut(&mut bytes).unwrap();
+/// s.make_ascii_uppercase();
+/// assert_eq!(s, "HELLO");
+/// assert!(str_from_utf8_mut(&mut [0xff]).is_err());
+/// ```
+pub const fn str_from_utf8_mut(v: &mut [u8]) -> Result<&mut str, Utf8Error> {
+    #[rustversion::since(1.87)]
+    #[allow(clippy::incompatible_msrv)]
+    const fn imp(v: &mut [u8]) -> Result<&mut str, Utf8Error> {
+        core::str::from_utf8_mut(v)
+    }
+    #[rustversion::before(1.87)]
+    const fn imp(v: &mut [u8]) -> Result<&mut str, Utf8Error> {
+        match core::str::from_utf8(v) {
+            // SAFETY: `v` was checked to be valid UTF-8
+            Ok(_) => Ok(unsafe { core::str::from_utf8_unchecked_mut(v) }),
+            Err(err) => Err(err),
+        }
+    }
+    imp(v)
+}
@@ -0,0 +1,205 @@
+//! Functions for working with raw pointers and [`NonNull`]
+//!
+//! Many functions in this module are generic over [`Reference`], meaning that they accept both
+//! shared and mutable references and preserve their mutability.
+
+use core::ptr::NonNull;
+
+pub use crate::mem::nonnull_from;
+
+mod hidden {
+    pub trait Sealed {}
+    impl<T: ?Sized> Sealed for &T {}
+    impl<T: ?Sized> Sealed for &mut T {}
+}
+
+/// Shared and mutable references.
+///
+/// This trait is sealed and implemented for `&T` and `&mut T`. It can be used as a bound to
+/// write functions that accept both kinds of references, like [`nonnull_from`] and [`ptr_from`].
+///
+/// # Example
+/// ```
+/// use const_util::ptr::{ptr_from, Reference};
+/// const fn addr_of<R: Reference<Referee = u8>>(r: R) -> R::Pointer {
+///     ptr_from(r)
+/// }
+/// let mut x = 1;
+/// let _: *const u8 = addr_of(&x);
+/// let _: *mut u8 = addr_of(&mut x);
+/// ```
+pub trait Reference: Into<NonNull<Self::Referee>> + hidden::Sealed {
+    /// The type behind the reference.
+    type Referee: ?Sized;
+    /// `*const Referee` for shared references and `*mut Referee` for mutable ones.
+    type Pointer: Copy;
+    /// Whether the reference is mutable.
+    const MUTABLE: bool;
+}
+impl<T: ?Sized> Reference for &T {
+    type Referee = T;
+    type Pointer = *const T;
+    const MUTABLE: bool = false;
+}
+impl<T: ?Sized> Reference for &mut T {
+    type Referee = T;
+    type Pointer = *mut T;
+    const MUTABLE: bool = true;
+}
+
+/// Converts `&T` into `*const T` and `&mut T` into `*mut T`.
+///
+/// This function is a const version of the `From<&T>` implementation of `*const T` and the
+/// `From<&mut T>` implementation of `*mut T`.
+///
+/// # Example
+/// ```
+/// use const_util::ptr::ptr_from;
+/// let mut x = 1;
+/// let ptr: *mut i32 = ptr_from(&mut x);
+/// unsafe { ptr.write(2) };
+/// assert_eq!(unsafe { ptr_from(&x).read() }, 2);
+/// ```
+pub const fn ptr_from<R: Reference>(src: R) -> R::Pointer {
+    // SAFETY: References have the same layout as the corresponding raw pointers
+    unsafe { crate::mem::transmute_checked(src) }
+}
+
+/// Converts an optional reference into an optional [`NonNull`] with the same mutability.
+///
+/// # Example
+/// ```
+/// use const_util::ptr::nonnull_from_option;
+/// use core::ptr::NonNull;
+/// let x = 1;
+/// assert_eq!(nonnull_from_option(Some(&x)), Some(NonNull::from(&x)));
+/// assert_eq!(nonnull_from_option(None::<&mut u8>), None);
+/// ```
+pub const fn nonnull_from_option<R: Reference>(src: Option<R>) -> Option<NonNull<R::Referee>> {
+    // SAFETY: `Option<&T>`, `Option<&mut T>` and `Option<NonNull<T>>` have the same layout, with
+    // `None` represented as null
+    unsafe { crate::mem::transmute_checked(src) }
+}
+
+/// Returns the length of a slice pointer.
+///
+/// # Example
+/// ```
+/// use const_util::ptr::nonnull_slice_len;
+/// use core::ptr::NonNull;
+/// assert_eq!(nonnull_slice_len(NonNull::from(&[1, 2][..])), 2);
+/// ```
+pub const fn nonnull_slice_len<T>(ptr: NonNull<[T]>) -> usize {
+    ptr.as_ptr().len()
+}
+
+const fn slice_size_fits<T>(len: usize) -> bool {
+    match size_of::<T>().checked_mul(len) {
+        Some(size) => size <= isize::MAX as usize,
+        None => false,
+    }
+}
+/// Creates a slice pointer from a pointer and a length, if the size of the slice does not exceed
+/// [`isize::MAX`].
+///
+/// Slices whose size exceeds [`isize::MAX`] cannot be turned into references.
+///
+/// # Example
+/// ```
+/// use const_util::ptr::checked_slice_from_raw_parts;
+/// let arr = [1u32, 2];
+/// let slice = checked_slice_from_raw_parts(arr.as_ptr(), 2).unwrap();
+/// assert_eq!(unsafe { &*slice }, arr);
+/// assert!(checked_slice_from_raw_parts(arr.as_ptr(), usize::MAX / 4).is_none());
+/// ```
+pub const fn checked_slice_from_raw_parts<T>(data: *const T, len: usize) -> Option<*const [T]> {
+    if !slice_size_fits::<T>(len) {
+        return None;
+    }
+    Some(core::ptr::slice_from_raw_parts(data, len))
+}
+/// Creates a mutable slice pointer from a pointer and a length, if the size of the slice does not
+/// exceed [`isize::MAX`].
+///
+/// # Example
+/// ```
+/// use const_util::ptr::checked_slice_from_raw_parts_mut;
+/// let mut arr = [1u32, 2];
+/// let slice = checked_slice_from_raw_parts_mut(arr.as_mut_ptr(), 2).unwrap();
+/// unsafe { (*slice)[1] = 3 };
+/// assert_eq!(arr, [1, 3]);
+/// assert!(checked_slice_from_raw_parts_mut(arr.as_mut_ptr(), usize::MAX / 4).is_none());
+/// ```
+pub const fn checked_slice_from_raw_parts_mut<T>(data: *mut T, len: usize) -> Option<*mut [T]> {
+    if !slice_size_fits::<T>(len) {
+        return None;
+    }
+    Some(core::ptr::slice_from_raw_parts_mut(data, len))
+}
+
+/// Returns the distance from `origin` to `ptr` in bytes, if `ptr` is not before `origin`.
+///
+/// This is a checked version of [`pointer::byte_offset_from`] that returns `None` instead of a
+/// negative distance.
+///
+/// # Safety
+/// The safety requirements of [`pointer::offset_from`] apply. In particular, `ptr` and `origin`
+/// must both be in bounds of the same allocated object or one byte past its end.
+///
+/// # Example
+/// ```
+/// use const_util::ptr::checked_byte_offset_from;
+/// let arr = [0u16; 4];
+/// let (start, end) = (arr.as_ptr(), arr.as_ptr_range().end);
+/// assert_eq!(unsafe { checked_byte_offset_from(end, start) }, Some(8));
+/// assert_eq!(unsafe { checked_byte_offset_from(start, end) }, None);
+/// ```
+///
+/// [`pointer::byte_offset_from`]: https://doc.rust-lang.org/std/primitive.pointer.html#method.byte_offset_from
+/// [`pointer::offset_from`]: https://doc.rust-lang.org/std/primitive.pointer.html#method.offset_from
+pub const unsafe fn checked_byte_offset_from<T: ?Sized, U: ?Sized>(
+    ptr: *const T,
+    origin: *const U,
+) -> Option<usize> {
+    // SAFETY: Guaranteed by the caller
+    let offset = unsafe { ptr.cast::<u8>().offset_from(origin.cast::<u8>()) };
+    if offset < 0 {
+        return None;
+    }
+    Some(offset as usize)
+}
+
+/// Returns the distance from `origin` to `ptr` in units of `T`, if `ptr` is not before `origin`
+/// and the distance in bytes is a multiple of the size of `T`.
+///
+/// This is a checked version of [`pointer::offset_from`] that returns `None` instead of a negative
+/// distance, a panic for zero-sized `T` or undefined behavior for distances that are not a
+/// multiple of the size of `T`.
+///
+/// # Safety
+/// The safety requirements of [`pointer::offset_from`] apply. In particular, `ptr` and `origin`
+/// must both be in bounds of the same allocated object or one byte past its end.
+///
+/// # Example
+/// ```
+/// use const_util::ptr::checked_offset_from;
+/// let arr = [0u16; 4];
+/// let (start, end) = (arr.as_ptr(), arr.as_ptr_range().end);
+/// assert_eq!(unsafe { checked_offset_from(end, start) }, Some(4));
+/// assert_eq!(unsafe { checked_offset_from(start, end) }, None);
+/// let odd = unsafe { start.cast::<u8>().add(1).cast::<u16>() };
+/// assert_eq!(unsafe { checked_offset_from(odd, start) }, None);
+/// ```
+///
+/// [`pointer::offset_from`]: https://doc.rust-lang.org/std/primitive.pointer.html#method.offset_from
+pub const unsafe fn checked_offset_from<T>(ptr: *const T, origin: *const T) -> Option<usize> {
+    // SAFETY: Guaranteed by the caller
+    let Some(bytes) = (unsafe { checked_byte_offset_from(ptr, origin) }) else {
+        return None;
+    };
+    match size_of::<T>() {
+        0 => None,
+        size if bytes % size != 0 => None,
+        size => Some(bytes / size),
+    }
+}
@@ -1,19 +1,301 @@
-//! Functions for unwrapping [`Result`]s
+//! Functions for unwrapping and combining [`Result`]s
+//!
+//! As in [`option`](crate::option), values that have to be discarded are leaked using
+//! [`core::mem::forget`].
 
-/// # Safety
-/// The matchee must be passed by value
+/// Moves the fields out of a variant of an enum, panicking if the value is a different variant.
+///
+/// This can be used on arbitrary enums with generics or drop glue in `const` contexts, where
+/// matching by value is not possible because the unmatched variants would have to be dropped.
+///
+/// The syntax is `expect_variant!(value, Path::Variant(fields...), message)`, where
+/// the fields are identifiers and the message is optional. Struct variants are supported using
+/// `Path::Variant { field, field: binding, ... }`. If the variant has exactly one field, the
+/// macro evaluates to it, otherwise it evaluates to a tuple of all of the fields, which can be
+/// taken apart using [`destruct_tuple`](crate::destruct_tuple). For variants
+/// with a single unnamed field, the field list can also be omitted entirely.
+///
+/// All fields have to be bound, since there is no way to drop an unbound field with generics or
+/// drop glue. If the enum implements [`Drop`], its destructor is not run. The value must be passed
+/// by value, passing a reference causes a compile error.
+///
+/// See [`try_variant`](crate::try_variant) for a non-panicking version.
+///
+/// # Example
+/// ```
+/// use const_util::expect_variant;
+/// enum Node<T> {
+///     Leaf(T),
+///     Branch { left: T, right: T },
+/// }
+/// const fn leaf<T>(node: Node<T>) -> T {
+///     expect_variant!(node, Node::Leaf)
+/// }
+/// const fn children<T>(node: Node<T>) -> [T; 2] {
+///     let fields = expect_variant!(node, Node::Branch { left, right }, "Not a branch");
+///     const_util::destruct_tuple! { left, right in fields }
+///     [left, right]
+/// }
+/// assert_eq!(leaf(Node::Leaf(String::from("a"))), "a");
+/// assert_eq!(
+///     children(Node::Branch { left: String::from("l"), right: String::from("r") }),
+///     ["l", "r"],
+/// );
+/// ```
+/// References are rejected:
+/// ```compile_fail
+/// use const_util::expect_variant;
+/// let opt = Some(String::new());
+/// let dup: String = expect_variant!(&opt, Some);
+/// ```
+#[macro_export]
 macro_rules! expect_variant {
-    ($ex:expr, $variant:path, $msg:expr) => {{
-        let val = ::core::mem::ManuallyDrop::new($ex);
-        let val = $crate::mem::man_drop_ref(&val);
-        let $variant(val) = val else {
-            panic!("{}", $msg);
-        };
-        // SAFETY: ManuallyDrop holds the pointee by value and is not used afterwards
-        ::core::ptr::read(val)
+    ($ex:expr, $($variant:ident)::+ $(, $msg:expr)? $(,)?) => {
+        $crate::expect_variant!($ex, $($variant)::+(__field) $(, $msg)?)
+    };
+    ($ex:expr, $($variant:ident)::+ ($($field:ident),* $(,)?) $(, $msg:expr)? $(,)?) => {
+        $crate::__variant_impl!(
+            $ex, [$($variant)::+ ($($field),*)], [$($field),*],
+            __val => $crate::__variant_mismatch!($($msg)?),
+            |payload| payload,
+        )
+    };
+    (
+        $ex:expr,
+        $($variant:ident)::+ { $($field:ident $(: $bind:ident)?),* $(,)? }
+        $(, $msg:expr)? $(,)?
+    ) => {
+        $crate::__variant_impl!(
+            $ex, [$($variant)::+ { $($field $(: $bind)?),* }], [$($crate::__first!($($bind)? $field)),*],
+            __val => $crate::__variant_mismatch!($($msg)?),
+            |payload| payload,
+        )
+    };
+}
+/// Non-panicking version of [`expect_variant`](crate::expect_variant).
+///
+/// Evaluates to `Ok(fields)` if the value is the given variant and to `Err(value)` with the
+/// original value otherwise. The syntax is the same as for `expect_variant`, without the message.
+///
+/// # Example
+/// ```
+/// use const_util::{try_variant, result::expect_err};
+/// enum Token<S> {
+///     Ident(S),
+///     Number(u64),
+/// }
+/// const fn ident<S>(tok: Token<S>) -> Result<S, Token<S>> {
+///     try_variant!(tok, Token::Ident)
+/// }
+/// assert_eq!(ident(Token::Ident(String::from("a"))).ok().unwrap(), "a");
+/// assert!(matches!(expect_err(ident(Token::<String>::Number(1)), ""), Token::Number(1)));
+/// ```
+#[macro_export]
+macro_rules! try_variant {
+    ($ex:expr, $($variant:ident)::+ $(,)?) => {
+        $crate::try_variant!($ex, $($variant)::+(__field))
+    };
+    ($ex:expr, $($variant:ident)::+ ($($field:ident),* $(,)?) $(,)?) => {
+        $crate::__variant_impl!(
+            $ex, [$($variant)::+ ($($field),*)], [$($field),*],
+            __val => $crate::__mac::core::result::Result::Err(
+                $crate::__mac::core::mem::ManuallyDrop::into_inner(__val)
+            ),
+            |payload| $crate::__mac::core::result::Result::Ok(payload),
+        )
+    };
+    ($ex:expr, $($variant:ident)::+ { $($field:ident $(: $bind:ident)?),* $(,)? } $(,)?) => {
+        $crate::__variant_impl!(
+            $ex, [$($variant)::+ { $($field $(: $bind)?),* }], [$($crate::__first!($($bind)? $field)),*],
+            __val => $crate::__mac::core::result::Result::Err(
+                $crate::__mac::core::mem::ManuallyDrop::into_inner(__val)
+            ),
+            |payload| $crate::__mac::core::result::Result::Ok(payload),
+        )
+    };
+}
+#[doc(hidden)]
+#[macro_export]
+macro_rules! __variant_impl {
+    (
+        $ex:expr, [$($pat:tt)*], [$($bind:expr),*],
+        $val:ident => $mismatch:expr,
+        |$payload:ident| $output:expr $(,)?
+    ) => {{
+        let $val = $crate::__mac::core::mem::ManuallyDrop::new($ex);
+        let __ref = $crate::mem::man_drop_ref(&$val);
+        $crate::__mac::assert_not_reference(__ref);
+        if let $($pat)* = __ref {
+            // SAFETY: The value is held by value in a `ManuallyDrop` that is not used afterwards,
+            // so moving the fields out of it is sound
+            let $payload = unsafe { ($($crate::__mac::core::ptr::read($bind)),*) };
+            $output
+        } else {
+            $mismatch
+        }
+    }};
+}
+#[doc(hidden)]
+#[macro_export]
+macro_rules! __variant_mismatch {
+    () => {
+        $crate::__mac::core::panic!("Attempted to call `expect_variant` on a different variant")
+    };
+    ($msg:expr) => {
+        $crate::__mac::core::panic!("{}", $msg)
+    };
+}
+
+/// Propagates `Err` and `None` values out of a `const fn`, like the `?` operator.
+///
+/// When applied to a `Result<T, E>`, evaluates to `T` or returns `Err(E)`. When applied to an
+/// `Option<T>`, evaluates to `T` or returns `None`. Unlike `?`, no [`From`] conversion is
+/// performed on the error. Instead, errors can be mapped using `map_err = <expr>`, where `<expr>`
+/// is anything that can be called like a `const fn`, such as a tuple variant.
+///
+/// Since the values are moved using [`unwrap_ok`](crate::result::unwrap_ok) and
+/// [`unwrap_err`](crate::result::unwrap_err), this works with generics and drop glue.
+///
+/// # Example
+/// ```
+/// use const_util::const_try;
+/// #[derive(Debug, PartialEq)]
+/// enum Error {
+///     Empty,
+///     Parse(u8),
+/// }
+/// const fn digit(b: u8) -> Result<u32, u8> {
+///     if b.is_ascii_digit() {
+///         Ok((b - b'0') as u32)
+///     } else {
+///         Err(b)
+///     }
+/// }
+/// const fn parse_two(s: &[u8]) -> Result<u32, Error> {
+///     let [a, b, ..] = *s else {
+///         return Err(Error::Empty);
+///     };
+///     Ok(const_try!(digit(a), map_err = Error::Parse) * 10
+///         + const_try!(digit(b), map_err = Error::Parse))
+/// }
+/// const fn first_two<T>(opt: Option<(T, T)>) -> Option<[T; 2]> {
+///     const_util::destruct_tuple! { a, b in const_try!(opt) }
+///     Some([a, b])
+/// }
+/// assert_eq!(parse_two(b"42"), Ok(42));
+/// assert_eq!(parse_two(b"4x"), Err(Error::Parse(b'x')));
+/// assert_eq!(first_two(Some((String::new(), String::new()))), Some([String::new(), String::new()]));
+/// assert_eq!(first_two::<String>(None), None);
+/// ```
+#[macro_export]
+macro_rules! const_try {
+    ($ex:expr $(,)?) => {{
+        let __branch = $crate::result::__try::branch($ex);
+        if __branch.is_ok() {
+            $crate::result::unwrap_ok(__branch)
+        } else {
+            return $crate::result::__try::from_residual($crate::result::unwrap_err(__branch));
+        }
+    }};
+    ($ex:expr, map_err = $map:expr $(,)?) => {{
+        let __res: $crate::__mac::core::result::Result<_, _> = $ex;
+        if __res.is_ok() {
+            $crate::result::unwrap_ok(__res)
+        } else {
+            return $crate::__mac::core::result::Result::Err($map($crate::result::unwrap_err(
+                __res,
+            )));
+        }
     }};
 }
 
+#[doc(hidden)]
+pub mod __try {
+    use super::{unwrap_err, unwrap_ok};
+    use crate::option::unwrap_some;
+    use core::{convert::Infallible, mem::ManuallyDrop};
+
+    /// # Safety
+    /// `Src` and `Dst` must be the same type
+    const unsafe fn transmute_same<Src, Dst>(src: Src) -> Dst {
+        let src = ManuallyDrop::new(src);
+        // SAFETY: `Src` and `Dst` are the same type, so this is a transmute from
+        // `ManuallyDrop<T>` to `T`
+        unsafe { core::mem::transmute_copy(&src) }
+    }
+
+    /// # Safety
+    /// If `IS_OPTION`, `Self` must be `Option<Output>` and `Residual` must be `Option<Infallible>`.
+    /// Otherwise, `Self` must be `Result<Output, Error>` and `Residual` must be
+    /// `Result<Infallible, Error>`.
+    pub unsafe trait Try {
+        type Output;
+        type Error;
+        type Residual;
+        const IS_OPTION: bool;
+    }
+    // SAFETY: trivial
+    unsafe impl<T, E> Try for Result<T, E> {
+        type Output = T;
+        type Error = E;
+        type Residual = Result<Infallible, E>;
+        const IS_OPTION: bool = false;
+    }
+    // SAFETY: trivial
+    unsafe impl<T> Try for Option<T> {
+        type Output = T;
+        type Error = Infallible;
+        type Residual = Option<Infallible>;
+        const IS_OPTION: bool = true;
+    }
+    /// # Safety
+    /// `Residual` must be `Self::Residual`
+    pub unsafe trait FromResidual<Residual>: Try {}
+    // SAFETY: trivial
+    unsafe impl<T, E> FromResidual<Result<Infallible, E>> for Result<T, E> {}
+    // SAFETY: trivial
+    unsafe impl<T> FromResidual<Option<Infallible>> for Option<T> {}
+
+    pub const fn branch<R: Try>(val: R) -> Result<R::Output, R::Residual> {
+        if R::IS_OPTION {
+            // SAFETY: `R` is `Option<R::Output>`
+            let opt: Option<R::Output> = unsafe { transmute_same(val) };
+            if opt.is_some() {
+                return Ok(unwrap_some(opt));
+            }
+            // `opt` is `None`, so nothing is leaked here
+            core::mem::forget(opt);
+            // SAFETY: `R::Residual` is `Option<Infallible>`
+            Err(unsafe { transmute_same::<Option<Infallible>, _>(None) })
+        } else {
+            // SAFETY: `R` is `Result<R::Output, R::Error>`
+            let res: Result<R::Output, R::Error> = unsafe { transmute_same(val) };
+            if res.is_ok() {
+                return Ok(unwrap_ok(res));
+            }
+            let residual: Result<Infallible, R::Error> = Err(unwrap_err(res));
+            // SAFETY: `R::Residual` is `Result<Infallible, R::Error>`
+            Err(unsafe { transmute_same(residual) })
+        }
+    }
+    pub const fn from_residual<Residual, R: FromResidual<Residual>>(residual: Residual) -> R {
+        if R::IS_OPTION {
+            // `residual` is `None`, so nothing is leaked here
+            core::mem::forget(residual);
+            // SAFETY: `R` is `Option<R::Output>`
+            unsafe { transmute_same::<Option<R::Output>, _>(None) }
+        } else {
+            // SAFETY: `Residual` is `R::Residual`, which is `Result<Infallible, R::Error>`
+            let residual: Result<Infallible, R::Error> = unsafe { transmute_same(residual) };
+            let res: Result<R::Output, R::Error> = Err(unwrap_err(residual));
+            // SAFETY: `R` is `Result<R::Output, R::Error>`
+            unsafe { transmute_same(res) }
+        }
+    }
+}
+
+use crate::fmt::{ConstDisplay, StrBuf};
+
 /// Const version of [`Result::expect`] without the [`Debug`] formatting.
 ///
 /// Can also be used in combination with [`Result::is_ok`] to match the result.
@@ -32,8 +314,7 @@ macro_rules! expect_variant {
 /// ```
 #[track_caller]
 pub const fn expect_ok<T, E>(res: Result<T, E>, message: &str) -> T {
-    // SAFETY: `res` is passed by value
-    unsafe { expect_variant!(res, Ok, message) }
+    crate::expect_variant!(res, Ok, message)
 }
 /// Const version of [`Result::unwrap`] without the [`Debug`] formatting.
 ///
@@ -73,8 +354,7 @@ pub const fn unwrap_ok<T, E>(res: Result<T, E>) -> T {
 /// ```
 #[track_caller]
 pub const fn expect_err<T, E>(res: Result<T, E>, message: &str) -> E {
-    // SAFETY: `res` is passed by value
-    unsafe { expect_variant!(res, Err, message) }
+    crate::expect_variant!(res, Err, message)
 }
 /// Const version of [`Result::unwrap_err`] without the [`Debug`] formatting.
 ///
@@ -96,3 +376,340 @@ pub const fn expect_err<T, E>(res: Result<T, E>, message: &str) -> E {
 pub const fn unwrap_err<T, E>(res: Result<T, E>) -> E {
     expect_err(res, "Attempted to call `unwrap_err` on an `Ok` variant")
 }
+
+/// The capacity of the buffer that the panic messages of [`expect_ok_display`] and
+/// [`unwrap_ok_display`] are rendered into.
+const DISPLAY_MSG_LEN: usize = 256;
+
+/// Like [`expect_ok`], but includes the rendered error in the panic message.
+///
+/// The panic message is `"{message}: {error}"`, truncated to 256 bytes.
+///
+/// # Example
+/// ```
+/// use const_util::result::expect_ok_display;
+/// const fn checked_div(a: u32, b: u32) -> Result<u32, &'static str> {
+///     match a.checked_div(b) {
+///         Some(q) => Ok(q),
+///         None => Err("division by zero"),
+///     }
+/// }
+/// assert_eq!(expect_ok_display(checked_div(6, 2), "Invalid input"), 3);
+/// let res = std::panic::catch_unwind(|| expect_ok_display(checked_div(1, 0), "Invalid input"));
+/// assert_eq!(
+///     *res.unwrap_err().downcast::<String>().unwrap(),
+///     "Invalid input: division by zero",
+/// );
+/// ```
+#[track_caller]
+pub const fn expect_ok_display<T, E: ConstDisplay>(res: Result<T, E>, message: &str) -> T {
+    if res.is_ok() {
+        return unwrap_ok(res);
+    }
+    let res = core::mem::ManuallyDrop::new(res);
+    let Err(err) = crate::mem::man_drop_ref(&res) else {
+        unreachable!()
+    };
+    let mut buf = StrBuf::<DISPLAY_MSG_LEN>::new();
+    buf.push_str(message);
+    buf.push_str(": ");
+    buf.push_display(err);
+    panic!("{}", buf.as_str())
+}
+/// Like [`unwrap_ok`], but includes the rendered error in the panic message.
+///
+/// # Example
+/// ```should_panic
+/// use const_util::result::unwrap_ok_display;
+/// unwrap_ok_display(Err::<(), u8>(42));
+/// ```
+#[track_caller]
+pub const fn unwrap_ok_display<T, E: ConstDisplay>(res: Result<T, E>) -> T {
+    expect_ok_display(
+        res,
+        "Attempted to call `unwrap_ok_display` on an `Err` variant",
+    )
+}
+
+/// Const version of [`Result::ok`].
+///
+/// Unlike [`Result::ok`], the error is leaked instead of dropped if `res` is `Err`.
+///
+/// # Example
+/// ```
+/// use const_util::result::ok;
+/// assert_eq!(ok(Ok::<_, ()>(String::from("a"))).as_deref(), Some("a"));
+/// assert_eq!(ok(Err::<i32, _>(String::from("a"))), None);
+/// ```
+pub const fn ok<T, E>(res: Result<T, E>) -> Option<T> {
+    if res.is_ok() {
+        Some(unwrap_ok(res))
+    } else {
+        core::mem::forget(res);
+        None
+    }
+}
+/// Const version of [`Result::err`].
+///
+/// Unlike [`Result::err`], the value is leaked instead of dropped if `res` is `Ok`.
+///
+/// # Example
+/// ```
+/// use const_util::result::err;
+/// assert_eq!(err(Err::<(), _>(String::from("a"))).as_deref(), Some("a"));
+/// assert_eq!(err(Ok::<_, String>(1)), None);
+/// ```
+pub const fn err<T, E>(res: Result<T, E>) -> Option<E> {
+    if res.is_err() {
+        Some(unwrap_err(res))
+    } else {
+        core::mem::forget(res);
+        None
+    }
+}
+/// Const version of [`Result::unwrap_or`].
+///
+/// Unlike [`Result::unwrap_or`], `default` is leaked instead of dropped if `res` is `Ok` and the
+/// error is leaked if `res` is `Err`.
+///
+/// # Example
+/// ```
+/// use const_util::result::unwrap_or;
+/// const fn or_empty<E>(res: Result<String, E>) -> String {
+///     unwrap_or(res, String::new())
+/// }
+/// assert_eq!(or_empty(Ok::<_, ()>(String::from("a"))), "a");
+/// assert_eq!(or_empty(Err(())), "");
+/// ```
+pub const fn unwrap_or<T, E>(res: Result<T, E>, default: T) -> T {
+    if res.is_ok() {
+        core::mem::forget(default);
+        unwrap_ok(res)
+    } else {
+        core::mem::forget(res);
+        default
+    }
+}
+/// Const version of the unstable `Result::into_ok`.
+///
+/// Unwraps a result whose error type cannot be instantiated, without the possibility of panicking.
+///
+/// # Example
+/// ```
+/// use const_util::result::into_ok;
+/// use core::convert::Infallible;
+/// const fn infallible<T>(value: T) -> Result<T, Infallible> {
+///     Ok(value)
+/// }
+/// assert_eq!(into_ok(infallible(String::from("a"))), "a");
+/// ```
+pub const fn into_ok<T>(res: Result<T, core::convert::Infallible>) -> T {
+    unwrap_ok(res)
+}
+/// Const version of the unstable `Result::into_err`.
+///
+/// Unwraps a result whose value type cannot be instantiated, without the possibility of panicking.
+///
+/// # Example
+/// ```
+/// use const_util::result::into_err;
+/// use core::convert::Infallible;
+/// assert_eq!(into_err(Err::<Infallible, _>(String::from("a"))), "a");
+/// ```
+pub const fn into_err<E>(res: Result<core::convert::Infallible, E>) -> E {
+    unwrap_err(res)
+}
+/// Const version of [`Result::flatten`].
+///
+/// No values are discarded by this function.
+///
+/// # Example
+/// ```
+/// use const_util::result::flatten;
+/// assert_eq!(flatten(Ok::<_, String>(Ok::<_, String>(1))), Ok(1));
+/// assert_eq!(flatten(Ok::<Result<i32, _>, _>(Err(String::from("a")))).unwrap_err(), "a");
+/// ```
+pub const fn flatten<T, E>(res: Result<Result<T, E>, E>) -> Result<T, E> {
+    if res.is_ok() {
+        unwrap_ok(res)
+    } else {
+        Err(unwrap_err(res))
+    }
+}
+/// Const version of [`Result::transpose`].
+///
+/// No values are discarded by this function.
+///
+/// # Example
+/// ```
+/// use const_util::result::transpose;
+/// assert_eq!(transpose(Ok::<Option<i32>, String>(Some(1))), Some(Ok(1)));
+/// assert_eq!(transpose(Ok::<Option<i32>, String>(None)), None);
+/// assert_eq!(transpose(Err::<Option<i32>, _>(())), Some(Err(())));
+/// ```
+pub const fn transpose<T, E>(res: Result<Option<T>, E>) -> Option<Result<T, E>> {
+    if res.is_err() {
+        return Some(Err(unwrap_err(res)));
+    }
+    let opt = unwrap_ok(res);
+    if opt.is_some() {
+        Some(Ok(crate::option::unwrap_some(opt)))
+    } else {
+        // `opt` is `None`, so nothing is leaked here
+        core::mem::forget(opt);
+        None
+    }
+}
+/// Const version of [`Option::transpose`].
+///
+/// This is the inverse of [`transpose`]. No values are discarded by this function.
+///
+/// # Example
+/// ```
+/// use const_util::result::transpose_option;
+/// assert_eq!(transpose_option(Some(Ok::<i32, String>(1))), Ok(Some(1)));
+/// assert_eq!(transpose_option(None::<Result<i32, String>>), Ok(None));
+/// assert_eq!(transpose_option(Some(Err::<i32, _>(()))), Err(()));
+/// ```
+pub const fn transpose_option<T, E>(opt: Option<Result<T, E>>) -> Result<Option<T>, E> {
+    if opt.is_none() {
+        // `opt` is `None`, so nothing is leaked here
+        core::mem::forget(opt);
+        return Ok(None);
+    }
+    let res = crate::option::unwrap_some(opt);
+    if res.is_ok() {
+        Ok(Some(unwrap_ok(res)))
+    } else {
+        Err(unwrap_err(res))
+    }
+}
+
+/// A small `Copy` error type for `const` code that carries static context.
+///
+/// It holds a `&'static str` message, an optional integer payload and up to
+/// [`MAX_CONTEXT`](Self::MAX_CONTEXT) context strings, which are added using
+/// [`context`](Self::context) as the error is propagated. If more contexts are added, the
+/// outermost ones are dropped and the rendered message is prefixed with `...`.
+///
+/// The error is rendered as `"{context}: ... : {context}: {message}: {payload}"`, with the
+/// outermost context first.
+///
+/// # Example
+/// ```
+/// use const_util::{const_try, result::ConstError};
+/// const fn parse_digit(b: u8) -> Result<u8, ConstError> {
+///     if b.is_ascii_digit() {
+///         Ok(b - b'0')
+///     } else {
+///         Err(ConstError::new("invalid digit").with_usize(b as usize))
+///     }
+/// }
+/// const fn parse_version(s: &[u8]) -> Result<u8, ConstError> {
+///     let [b] = *s else {
+///         return Err(ConstError::new("invalid length").with_usize(s.len()));
+///     };
+///     match parse_digit(b) {
+///         Ok(d) => Ok(d),
+///         Err(err) => Err(err.context("parsing version")),
+///     }
+/// }
+/// const VERSION: u8 = match parse_version(b"7") {
+///     Ok(v) => v,
+///     Err(err) => err.panic_with(),
+/// };
+/// assert_eq!(VERSION, 7);
+/// assert_eq!(
+///     parse_version(b"x").unwrap_err().to_string(),
+///     "parsing version: invalid digit: 120",
+/// );
+/// ```
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Hash)]
+pub struct ConstError {
+    message: &'static str,
+    payload: Option<i128>,
+    context: [&'static str; ConstError::MAX_CONTEXT],
+    context_len: usize,
+    context_truncated: bool,
+}
+impl ConstError {
+    /// The maximum number of context strings that are kept.
+    pub const MAX_CONTEXT: usize = 4;
+    /// The capacity of the buffer that [`panic_with`](Self::panic_with) renders into.
+    pub const MSG_BUF_LEN: usize = 512;
+
+    /// Creates an error with the given message and no payload or context.
+    pub const fn new(message: &'static str) -> Self {
+        Self {
+            message,
+            payload: None,
+            context: [""; Self::MAX_CONTEXT],
+            context_len: 0,
+            context_truncated: false,
+        }
+    }
+    /// Sets the payload to a `usize`.
+    pub const fn with_usize(self, payload: usize) -> Self {
+        self.with_int(payload as i128)
+    }
+    /// Sets the payload to an `i128`.
+    pub const fn with_int(mut self, payload: i128) -> Self {
+        self.payload = Some(payload);
+        self
+    }
+    /// Adds a context string that describes what was being done when the error occurred.
+    pub const fn context(mut self, context: &'static str) -> Self {
+        if self.context_len < Self::MAX_CONTEXT {
+            self.context[self.context_len] = context;
+            self.context_len += 1;
+        } else {
+            self.context_truncated = true;
+        }
+        self
+    }
+    /// Returns the message.
+    pub const fn message(&self) -> &'static str {
+        self.message
+    }
+    /// Returns the payload.
+    pub const fn payload(&self) -> Option<i128> {
+        self.payload
+    }
+    /// Returns the context strings that were kept, starting with the innermost.
+    pub const fn contexts(&self) -> &[&'static str] {
+        self.context.split_at(self.context_len).0
+    }
+    /// Renders the error into `buf`.
+    pub const fn render<const N: usize>(&self, buf: &mut StrBuf<N>) {
+        if self.context_truncated {
+            buf.push_str("...: ");
+        }
+        let mut contexts = self.contexts();
+        while let [rest @ .., last] = contexts {
+            buf.push_str(last);
+            buf.push_str(": ");
+            contexts = rest;
+        }
+        buf.push_str(self.message);
+        if let Some(payload) = self.payload {
+            buf.push_str(": ");
+            buf.push_display(&payload);
+        }
+    }
+    /// Panics with the rendered error, truncated to [`MSG_BUF_LEN`](Self::MSG_BUF_LEN) bytes.
+    #[track_caller]
+    #[cold]
+    pub const fn panic_with(self) -> ! {
+        let mut buf = StrBuf::<{ Self::MSG_BUF_LEN }>::new();
+        self.render(&mut buf);
+        panic!("{}", buf.as_str())
+    }
+}
+impl core::fmt::Display for ConstError {
+    fn fmt(&self, f: &mut core::fmt::Formatter<'_>) -> core::fmt::Result {
+        let mut buf = StrBuf::<{ Self::MSG_BUF_LEN }>::new();
+        self.render(&mut buf);
+        f.write_str(buf.as_str())
+    }
+}
+impl core::error::Error for ConstError {}
@@ -0,0 +1,375 @@
+//! Functions for performing set operations on const slices
+//!
+//! The inputs are passed as [`Const`]s, while the outputs are computed at compile time and
+//! returned as `&'static` slices. All outputs contain every element at most once, in the order of
+//! their first occurrence in the inputs.
+//!
+//! The elements must implement the sealed [`SetElement`] trait, which is implemented for the
+//! integer primitives, [`bool`], [`char`] and `&'static str`.
+
+use crate::Const;
+
+mod hidden {
+    #[repr(u8)]
+    pub enum ElementKind {
+        U8,
+        U16,
+        U32,
+        U64,
+        U128,
+        Usize,
+        I8,
+        I16,
+        I32,
+        I64,
+        I128,
+        Isize,
+        Bool,
+        Char,
+        Str,
+    }
+    /// # Safety
+    /// `KIND` must be unique such that we can cast back to `Self` based on it.
+    pub unsafe trait SetElement: Copy + 'static {
+        const KIND: ElementKind;
+        const DEFAULT: Self;
+    }
+    macro_rules! impl_set_element {
+        ($($ty:ty => $kind:ident, $default:expr;)*) => {$(
+            // SAFETY: `KIND` is unique
+            unsafe impl SetElement for $ty {
+                const KIND: ElementKind = ElementKind::$kind;
+                const DEFAULT: Self = $default;
+            }
+        )*};
+    }
+    impl_set_element! {
+        u8 => U8, 0;
+        u16 => U16, 0;
+        u32 => U32, 0;
+        u64 => U64, 0;
+        u128 => U128, 0;
+        usize => Usize, 0;
+        i8 => I8, 0;
+        i16 => I16, 0;
+        i32 => I32, 0;
+        i64 => I64, 0;
+        i128 => I128, 0;
+        isize => Isize, 0;
+        bool => Bool, false;
+        char => Char, '\0';
+        &'static str => Str, "";
+    }
+}
+pub use hidden::SetElement;
+use hidden::*;
+
+const fn str_eq(lhs: &str, rhs: &str) -> bool {
+    let (lhs, rhs) = (lhs.as_bytes(), rhs.as_bytes());
+    if lhs.len() != rhs.len() {
+        return false;
+    }
+    let mut i = 0;
+    while i < lhs.len() {
+        if lhs[i] != rhs[i] {
+            return false;
+        }
+        i += 1;
+    }
+    true
+}
+
+const fn element_eq<T: SetElement>(lhs: &T, rhs: &T) -> bool {
+    macro_rules! dispatch {
+        ($($kind:ident => $ty:ty),*) => {
+            match T::KIND {
+                $(ElementKind::$kind => {
+                    // SAFETY: `KIND` uniquely identifies the implementor, so `T` is `$ty`
+                    let (lhs, rhs) = unsafe {
+                        (&*(lhs as *const T).cast::<$ty>(), &*(rhs as *const T).cast::<$ty>())
+                    };
+                    *lhs == *rhs
+                })*
+                ElementKind::Str => {
+                    // SAFETY: `KIND` uniquely identifies the implementor, so `T` is `&str`
+                    let (lhs, rhs) = unsafe {
+                        (*(lhs as *const T).cast::<&str>(), *(rhs as *const T).cast::<&str>())
+                    };
+                    str_eq(lhs, rhs)
+                }
+            }
+        };
+    }
+    dispatch! {
+        U8 => u8, U16 => u16, U32 => u32, U64 => u64, U128 => u128, Usize => usize,
+        I8 => i8, I16 => i16, I32 => i32, I64 => i64, I128 => i128, Isize => isize,
+        Bool => bool, Char => char
+    }
+}
+
+const fn contains<T: SetElement>(slice: &[T], elem: &T) -> bool {
+    let mut slice = slice;
+    while let [first, rest @ ..] = slice {
+        if element_eq(first, elem) {
+            return true;
+        }
+        slice = rest;
+    }
+    false
+}
+
+const DEDUP: u8 = 0;
+const UNION: u8 = 1;
+const INTERSECTION: u8 = 2;
+const DIFFERENCE: u8 = 3;
+
+/// Applies the operation `OP` to `lhs` and `rhs`, writing as much of the output to `out` as
+/// fits and returning the length of the full output.
+const fn apply<const OP: u8, T: SetElement>(lhs: &[T], rhs: &[T], out: &mut [T]) -> usize {
+    // The candidates are `lhs`, followed by `rhs` for unions
+    let (lhs_len, rhs_len) = (lhs.len(), if OP == UNION { rhs.len() } else { 0 });
+    let mut len = 0;
+    let mut i = 0;
+    while i < lhs_len + rhs_len {
+        let (elem, is_repeated) = if i < lhs_len {
+            (&lhs[i], contains(lhs.split_at(i).0, &lhs[i]))
+        } else {
+            let elem = &rhs[i - lhs_len];
+            let is_repeated = contains(lhs, elem) || contains(rhs.split_at(i - lhs_len).0, elem);
+            (elem, is_repeated)
+        };
+        let keep = !is_repeated
+            && match OP {
+                INTERSECTION => contains(rhs, elem),
+                DIFFERENCE => !contains(rhs, elem),
+                _ => true,
+            };
+        if keep {
+            if len < out.len() {
+                out[len] = *elem;
+            }
+            len += 1;
+        }
+        i += 1;
+    }
+    len
+}
+
+struct Empty<T>(T);
+impl<T: SetElement> Const for Empty<T> {
+    type Type = &'static [T];
+    const VALUE: Self::Type = &[];
+}
+
+/// Casts the slice from `C` to a slice of `U` if `U` is the element type of `C`, otherwise
+/// evaluates to an empty slice.
+///
+/// The latter case is needed because the evaluation is instantiated for every element type.
+struct Cast<C, U>(C, U);
+impl<'a, T: SetElement, C: Const<Type = &'a [T]>, U: SetElement> Const for Cast<C, U> {
+    type Type = &'a [U];
+    const VALUE: Self::Type = if T::KIND as u8 == U::KIND as u8 {
+        // SAFETY: `KIND` uniquely identifies the implementor, so `T` and `U` are the same type
+        unsafe { &*(crate::value_of::<C>() as *const [T] as *const [U]) }
+    } else {
+        &[]
+    };
+}
+
+// The evaluation is implemented separately for each element type, since the compiler does not
+// allow promoting arrays of a generic type to `'static`.
+struct Apply<T, Lhs, Rhs, const OP: u8>(T, Lhs, Rhs);
+macro_rules! impl_apply {
+    ($($ty:ty),*) => {$(
+        generic_upper_bound::impl_accept_upper_bound! {
+            impl{'a, 'b, Lhs: Const<Type = &'a [$ty]>, Rhs: Const<Type = &'b [$ty]>, const OP: u8}
+                Apply<$ty, Lhs, Rhs, OP>;
+            const DESIRED_GENERIC: usize =
+                apply::<OP, $ty>(crate::value_of::<Lhs>(), crate::value_of::<Rhs>(), &mut []);
+            const EVAL<const N: usize>: &'static [$ty] = &{
+                let mut out = [<$ty as SetElement>::DEFAULT; N];
+                apply::<OP, $ty>(crate::value_of::<Lhs>(), crate::value_of::<Rhs>(), &mut out);
+                out
+            };
+        }
+    )*};
+}
+impl_apply!(
+    u8,
+    u16,
+    u32,
+    u64,
+    u128,
+    usize,
+    i8,
+    i16,
+    i32,
+    i64,
+    i128,
+    isize,
+    bool,
+    char,
+    &'static str
+);
+
+const fn eval<'a, 'b, T, Lhs, Rhs, const OP: u8>() -> &'static [T]
+where
+    T: SetElement,
+    Lhs: Const<Type = &'a [T]>,
+    Rhs: Const<Type = &'b [T]>,
+{
+    use generic_upper_bound as gub;
+    macro_rules! dispatch {
+        ($($kind:ident => $ty:ty),*) => {
+            match T::KIND {
+                $(ElementKind::$kind => {
+                    type A<Lhs, Rhs, const OP: u8> = Apply<$ty, Cast<Lhs, $ty>, Cast<Rhs, $ty>, OP>;
+                    let out = gub::eval_with_upper_bound::<A<Lhs, Rhs, OP>>()
+                        .split_at(gub::desired_generic::<A<Lhs, Rhs, OP>>())
+                        .0;
+                    // SAFETY: `KIND` uniquely identifies the implementor, so `T` is `$ty`
+                    unsafe { &*(out as *const [$ty] as *const [T]) }
+                })*
+            }
+        };
+    }
+    dispatch! {
+        U8 => u8, U16 => u16, U32 => u32, U64 => u64, U128 => u128, Usize => usize,
+        I8 => i8, I16 => i16, I32 => i32, I64 => i64, I128 => i128, Isize => isize,
+        Bool => bool, Char => char, Str => &'static str
+    }
+}
+
+/// Removes all repeated elements from a slice at compile time, keeping the first occurrence.
+///
+/// # Example
+/// ```
+/// use const_util::{Const, set::dedup};
+/// struct Ids;
+/// impl Const for Ids {
+///     type Type = &'static [u32];
+///     const VALUE: Self::Type = &[3, 1, 3, 2, 1];
+/// }
+/// assert_eq!(dedup::<u32, Ids>(), [3, 1, 2]);
+/// ```
+pub const fn dedup<'a, T, Elems>() -> &'static [T]
+where
+    T: SetElement,
+    Elems: Const<Type = &'a [T]>,
+{
+    eval::<T, Elems, Empty<T>, DEDUP>()
+}
+
+/// Computes the union of two slices at compile time.
+///
+/// The output contains the elements of `Lhs`, followed by the elements of `Rhs` that are not in
+/// `Lhs`.
+///
+/// # Example
+/// ```
+/// use const_util::{Const, set::union};
+/// struct Lhs;
+/// impl Const for Lhs {
+///     type Type = &'static [&'static str];
+///     const VALUE: Self::Type = &["read", "write"];
+/// }
+/// struct Rhs;
+/// impl Const for Rhs {
+///     type Type = &'static [&'static str];
+///     const VALUE: Self::Type = &["exec", "write"];
+/// }
+/// assert_eq!(union::<&str, Lhs, Rhs>(), ["read", "write", "exec"]);
+/// ```
+pub const fn union<'a, T, Lhs, Rhs>() -> &'static [T]
+where
+    T: SetElement,
+    Lhs: Const<Type = &'a [T]>,
+    Rhs: Const<Type = &'a [T]>,
+{
+    eval::<T, Lhs, Rhs, UNION>()
+}
+
+/// Computes the intersection of two slices at compile time.
+///
+/// The output contains the elements of `Lhs` that are also in `Rhs`.
+///
+/// # Example
+/// ```
+/// use const_util::{Const, set::intersection};
+/// struct Lhs;
+/// impl Const for Lhs {
+///     type Type = &'static [char];
+///     const VALUE: Self::Type = &['a', 'b', 'c'];
+/// }
+/// struct Rhs;
+/// impl Const for Rhs {
+///     type Type = &'static [char];
+///     const VALUE: Self::Type = &['c', 'd', 'a'];
+/// }
+/// assert_eq!(intersection::<char, Lhs, Rhs>(), ['a', 'c']);
+/// ```
+pub const fn intersection<'a, T, Lhs, Rhs>() -> &'static [T]
+where
+    T: SetElement,
+    Lhs: Const<Type = &'a [T]>,
+    Rhs: Const<Type = &'a [T]>,
+{
+    eval::<T, Lhs, Rhs, INTERSECTION>()
+}
+
+/// Computes the difference of two slices at compile time.
+///
+/// The output contains the elements of `Lhs` that are not in `Rhs`.
+///
+/// # Example
+/// ```
+/// use const_util::{Const, set::difference};
+/// struct Lhs;
+/// impl Const for Lhs {
+///     type Type = &'static [i8];
+///     const VALUE: Self::Type = &[1, 2, 3, 2];
+/// }
+/// struct Rhs;
+/// impl Const for Rhs {
+///     type Type = &'static [i8];
+///     const VALUE: Self::Type = &[3];
+/// }
+/// assert_eq!(difference::<i8, Lhs, Rhs>(), [1, 2]);
+/// ```
+pub const fn difference<'a, T, Lhs, Rhs>() -> &'static [T]
+where
+    T: SetElement,
+    Lhs: Const<Type = &'a [T]>,
+    Rhs: Const<Type = &'a [T]>,
+{
+    eval::<T, Lhs, Rhs, DIFFERENCE>()
+}
+
+/// Checks whether a slice contains any element more than once.
+///
+/// Unlike the other functions in this module, this function accepts a regular slice.
+///
+/// # Example
+/// Combining the IDs of two crates and ensuring that there are no duplicates at compile time:
+/// ```
+/// use const_util::{Const, concat::concat_bytes2, set::is_unique};
+/// struct IdsA;
+/// impl Const for IdsA {
+///     type Type = &'static [u8];
+///     const VALUE: Self::Type = &[1, 2, 3];
+/// }
+/// struct IdsB;
+/// impl Const for IdsB {
+///     type Type = &'static [u8];
+///     const VALUE: Self::Type = &[4, 5];
+/// }
+/// const IDS: &[u8] = concat_bytes2::<IdsA, IdsB>();
+/// const _: () = assert!(is_unique(IDS), "duplicate ID");
+/// ```
+/// ```compile_fail
+/// # use const_util::set::is_unique;
+/// const _: () = assert!(is_unique(&["a", "b", "a"]), "duplicate ID");
+/// ```
+pub const fn is_unique<T: SetElement>(elems: &[T]) -> bool {
+    apply::<DEDUP, T>(elems, &[], &mut []) == elems.len()
+}
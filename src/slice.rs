@@ -110,110 +110,246 @@ const fn into_range<R: RangeIndex>(range: R, len: usize) -> Option<Range<usize>>
     })
 }
 
-/// # Safety
-/// `slice` must come from a mutable or immutable reference. The returned pointer is valid for
-/// reborrowing as a subslice with the same mutability as the original reference.
-const unsafe fn slice_get_nonnull<T, R>(slice: NonNull<[T]>, range: R) -> Option<NonNull<[T]>>
+use crate::fmt::{write_str, write_u128, USIZE_STR_LEN};
+
+/// The reason for a range being invalid for indexing a slice.
+///
+/// Returned by [`try_slice_index`] and [`try_slice_index_mut`].
+///
+/// # Example
+/// ```
+/// use const_util::slice::{try_slice_index, SliceIndexError};
+/// assert_eq!(
+///     try_slice_index(&[1, 2, 3], 1..5),
+///     Err(SliceIndexError::EndOutOfRange { end: 5, len: 3 }),
+/// );
+/// assert_eq!(
+///     try_slice_index(&[1, 2, 3], ..=usize::MAX),
+///     Err(SliceIndexError::Overflow),
+/// );
+/// let err = SliceIndexError::StartAfterEnd { start: 2, end: 1 };
+/// assert_eq!(
+///     err.message(&mut [0; SliceIndexError::MSG_BUF_LEN]),
+///     "slice index starts at 2 but ends at 1",
+/// );
+/// ```
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Hash)]
+pub enum SliceIndexError {
+    /// The end of the range (or its start, if it is excluded) is `usize::MAX` and cannot be
+    /// converted to an exclusive bound.
+    Overflow,
+    /// The start of the range is greater than its end.
+    StartAfterEnd {
+        /// The inclusive start of the range
+        start: usize,
+        /// The exclusive end of the range
+        end: usize,
+    },
+    /// The end of the range is greater than the length of the slice.
+    EndOutOfRange {
+        /// The exclusive end of the range
+        end: usize,
+        /// The length of the slice
+        len: usize,
+    },
+}
+impl SliceIndexError {
+    const OVERFLOW_MSG: &str = "attempted to index slice after maximum allowed usize";
+    const START_AFTER_END_MSG: (&str, &str) = ("slice index starts at ", " but ends at ");
+    const END_OUT_OF_RANGE_MSG: (&str, &str) =
+        ("range end index ", " is out of range for slice of length ");
+
+    /// A buffer length that is sufficient for every message rendered by [`Self::message`].
+    pub const MSG_BUF_LEN: usize = {
+        let (lhs, mid) = Self::END_OUT_OF_RANGE_MSG;
+        let len = lhs.len() + mid.len() + 2 * USIZE_STR_LEN;
+        assert!(len >= Self::OVERFLOW_MSG.len());
+        let (lhs, mid) = Self::START_AFTER_END_MSG;
+        assert!(len >= lhs.len() + mid.len() + 2 * USIZE_STR_LEN);
+        len
+    };
+
+    /// Renders the message that [`slice_index`] panics with for this error into `buf`.
+    pub const fn message<'a>(&self, buf: &'a mut [u8; Self::MSG_BUF_LEN]) -> &'a str {
+        let ((lhs, mid), left, right) = match *self {
+            Self::Overflow => return Self::OVERFLOW_MSG,
+            Self::StartAfterEnd { start, end } => (Self::START_AFTER_END_MSG, start, end),
+            Self::EndOutOfRange { end, len } => (Self::END_OUT_OF_RANGE_MSG, end, len),
+        };
+        let rem = write_str(lhs, buf);
+        let rem = write_u128(left as u128, rem);
+        let rem = write_str(mid, rem);
+        let rem_len = write_u128(right as u128, rem).len();
+        match core::str::from_utf8(buf.split_at(Self::MSG_BUF_LEN - rem_len).0) {
+            Ok(msg) => msg,
+            Err(_) => unreachable!(),
+        }
+    }
+
+    /// Panics with the message rendered by [`Self::message`].
+    #[track_caller]
+    #[cold]
+    pub const fn panic(self) -> ! {
+        panic!("{}", self.message(&mut [0; Self::MSG_BUF_LEN]))
+    }
+}
+impl core::fmt::Display for SliceIndexError {
+    fn fmt(&self, f: &mut core::fmt::Formatter<'_>) -> core::fmt::Result {
+        f.write_str(self.message(&mut [0; Self::MSG_BUF_LEN]))
+    }
+}
+impl core::error::Error for SliceIndexError {}
+
+/// Validates `range` for a slice of length `len`, returning the resulting exclusive range with
+/// `start <= end <= len`.
+const fn checked_range<R>(range: R, len: usize) -> Result<Range<usize>, SliceIndexError>
 where
     R: RangeIndex,
 {
-    let Some(Range { start, end }) = into_range(range, slice.len()) else {
-        return None;
-    };
-    let new_len = match usize::checked_sub(end, start) {
-        Some(it) if end <= slice.len() => it,
-        _ => return None,
+    let Some(Range { start, end }) = into_range(range, len) else {
+        return Err(SliceIndexError::Overflow);
     };
-    // SAFETY: `slice` came from a reference and `start <= end < slice.len()`, so the pointer
-    // addition is in-bounds. The returned pointer can reborrowed as a valid subslice with the
-    // same mutability because `start + new_len = start + end - start = end < slice.len()`.
-    Some(unsafe { NonNull::slice_from_raw_parts(slice.cast::<T>().add(start), new_len) })
+    if start > end {
+        Err(SliceIndexError::StartAfterEnd { start, end })
+    } else if end > len {
+        Err(SliceIndexError::EndOutOfRange { end, len })
+    } else {
+        Ok(start..end)
+    }
+}
+
+/// # Safety
+/// `slice` must satisfy the requirements of [`slice_get_mut_ptr`] and
+/// `range.start <= range.end <= slice.len()`
+const unsafe fn subslice_ptr<T>(slice: *mut [T], range: Range<usize>) -> *mut [T] {
+    let Range { start, end } = range;
+    // SAFETY: `slice` lies within a single allocation and `start <= end <= slice.len()`, so the
+    // pointer addition is in-bounds. The returned pointer covers a subrange of `slice` because
+    // `start + (end - start) = end <= slice.len()`.
+    unsafe { core::ptr::slice_from_raw_parts_mut(slice.cast::<T>().add(start), end - start) }
 }
+
+/// Const equivalent of [`<[T]>::get`](slice::get) for ranges on raw mutable slice pointers.
+///
+/// Returns a pointer to the subslice, or `None` if the range is out of bounds. The checks are
+/// the same as in [`slice_get`].
+///
 /// # Safety
-/// `slice` must come from a mutable or immutable reference. The returned pointer is valid for
-/// reborrowing as a subslice with the same mutability as the original reference.
-#[track_caller]
-const unsafe fn slice_index_nonnull<T, R>(slice: NonNull<[T]>, range: R) -> NonNull<[T]>
+/// The memory range described by `slice`, i.e. `slice.len()` elements starting at the
+/// address of `slice`, must lie within a single allocated object, as required by
+/// [`pointer::add`](https://doc.rust-lang.org/core/primitive.pointer.html#method.add). The pointee does not need to be initialized or otherwise valid.
+///
+/// # Example
+/// ```
+/// use const_util::slice::slice_get_mut_ptr;
+/// let mut arr = [1, 2, 3, 4];
+/// let ptr: *mut [i32] = &mut arr;
+/// unsafe {
+///     let sub = slice_get_mut_ptr(ptr, 1..3).unwrap();
+///     assert_eq!(sub.len(), 2);
+///     sub.cast::<i32>().write(5);
+///     assert!(slice_get_mut_ptr(ptr, 3..5).is_none());
+/// }
+/// assert_eq!(arr, [1, 5, 3, 4]);
+/// ```
+pub const unsafe fn slice_get_mut_ptr<T, R>(slice: *mut [T], range: R) -> Option<*mut [T]>
 where
     R: RangeIndex,
 {
-    #[track_caller]
-    #[cold]
-    const fn build_msg_panic<const MSG_LEN: usize>(
-        msg_lhs: &str,
-        left_usize: usize,
-        msg_mid: &str,
-        right_usize: usize,
-    ) -> ! {
-        const fn write_str_usize(mut n: usize, mut to: &mut [u8]) -> &mut [u8] {
-            while let [slot, rem @ ..] = to {
-                let brk = n < 10;
-
-                *slot = (n % 10) as u8 + b'0';
-                n /= 10;
-                to = rem;
-
-                if brk {
-                    break;
-                }
-            }
-            to
-        }
-        let mut msg = [0; MSG_LEN];
-        let (lhs, rem) = msg.split_at_mut(msg_lhs.len());
-        lhs.copy_from_slice(msg_lhs.as_bytes());
-        let rem = write_str_usize(left_usize, rem);
-        let (mid, rem) = rem.split_at_mut(msg_mid.len());
-        mid.copy_from_slice(msg_mid.as_bytes());
-        let rem = write_str_usize(right_usize, rem);
-        let rem_len = rem.len();
-        match core::str::from_utf8(msg.split_at(MSG_LEN - rem_len).0) {
-            Ok(msg) => panic!("{}", msg),
-            Err(_) => unreachable!(),
-        }
+    match checked_range(range, slice.len()) {
+        // SAFETY: Guaranteed by caller and `checked_range`
+        Ok(range) => Some(unsafe { subslice_ptr(slice, range) }),
+        Err(_) => None,
     }
-    const USIZE_STR_LEN: usize = {
-        let mut len = 1;
-        let mut n = usize::MAX;
-        while n >= 10 {
-            n /= 10;
-            len += 1;
-        }
-        len
-    };
-
-    let Some(Range { start, end }) = into_range(range, slice.len()) else {
-        const fn overflow_fail() -> ! {
-            panic!("attempted to index slice after maximum allowed usize")
-        }
-        overflow_fail()
-    };
-    let Some(new_len) = usize::checked_sub(end, start) else {
-        #[track_caller]
-        #[cold]
-        const fn bounds_order_fail(start: usize, end: usize) -> ! {
-            const LHS: &str = "slice index starts at ";
-            const MID: &str = " but ends at ";
-            const MSG_LEN: usize = LHS.len() + MID.len() + 2 * USIZE_STR_LEN;
-            build_msg_panic::<MSG_LEN>(LHS, start, MID, end)
-        }
-        bounds_order_fail(start, end)
-    };
-    if end > slice.len() {
-        #[track_caller]
-        #[cold]
-        const fn end_too_large_fail(index: usize, len: usize) -> ! {
-            const LHS: &str = "range end index ";
-            const MID: &str = " is out of range for slice of length ";
-            const MSG_LEN: usize = LHS.len() + MID.len() + 2 * USIZE_STR_LEN;
-            build_msg_panic::<MSG_LEN>(LHS, index, MID, len)
-        }
-        end_too_large_fail(end, slice.len());
+}
+/// Const equivalent of [`<[T]>::get`](slice::get) for ranges on raw slice pointers.
+///
+/// See [`slice_get_mut_ptr`].
+///
+/// # Safety
+/// See [`slice_get_mut_ptr`].
+pub const unsafe fn slice_get_ptr<T, R>(slice: *const [T], range: R) -> Option<*const [T]>
+where
+    R: RangeIndex,
+{
+    // SAFETY: Guaranteed by caller
+    match unsafe { slice_get_mut_ptr(slice.cast_mut(), range) } {
+        Some(ptr) => Some(ptr.cast_const()),
+        None => None,
+    }
+}
+/// Const equivalent of [`<[T]>::get`](slice::get) for ranges on [`NonNull`] slice pointers.
+///
+/// See [`slice_get_mut_ptr`].
+///
+/// # Safety
+/// See [`slice_get_mut_ptr`].
+pub const unsafe fn slice_get_nonnull<T, R>(slice: NonNull<[T]>, range: R) -> Option<NonNull<[T]>>
+where
+    R: RangeIndex,
+{
+    // SAFETY: Guaranteed by caller
+    match unsafe { slice_get_mut_ptr(slice.as_ptr(), range) } {
+        // SAFETY: An in-bounds offset of a non-null pointer is non-null
+        Some(ptr) => Some(unsafe { NonNull::new_unchecked(ptr) }),
+        None => None,
     }
-    // SAFETY: `slice` came from a reference and `start <= end < slice.len()`, so the pointer
-    // addition is in-bounds. The returned pointer can reborrowed as a valid subslice with the
-    // same mutability because `start + new_len = start + end - start = end < slice.len()`.
-    unsafe { NonNull::slice_from_raw_parts(slice.cast::<T>().add(start), new_len) }
+}
+/// Const equivalent of [`<[T]>::index_mut`](core::ops::IndexMut::index_mut) for ranges on raw mutable slice
+/// pointers.
+///
+/// Returns a pointer to the subslice. The checks and panic messages are the same as in
+/// [`slice_index`].
+///
+/// # Safety
+/// See [`slice_get_mut_ptr`].
+///
+/// # Panics
+/// If the range is out of bounds.
+#[track_caller]
+pub const unsafe fn slice_index_mut_ptr<T, R>(slice: *mut [T], range: R) -> *mut [T]
+where
+    R: RangeIndex,
+{
+    match checked_range(range, slice.len()) {
+        // SAFETY: Guaranteed by caller and `checked_range`
+        Ok(range) => unsafe { subslice_ptr(slice, range) },
+        Err(err) => err.panic(),
+    }
+}
+/// Const equivalent of [`<[T]>::index`](core::ops::Index::index) for ranges on raw slice pointers.
+///
+/// See [`slice_index_mut_ptr`].
+///
+/// # Safety
+/// See [`slice_get_mut_ptr`].
+///
+/// # Panics
+/// If the range is out of bounds.
+#[track_caller]
+pub const unsafe fn slice_index_ptr<T, R>(slice: *const [T], range: R) -> *const [T]
+where
+    R: RangeIndex,
+{
+    // SAFETY: Guaranteed by caller
+    unsafe { slice_index_mut_ptr(slice.cast_mut(), range).cast_const() }
+}
+/// Const equivalent of [`<[T]>::index`](core::ops::Index::index) for ranges on [`NonNull`] slice pointers.
+///
+/// See [`slice_index_mut_ptr`].
+///
+/// # Safety
+/// See [`slice_get_mut_ptr`].
+///
+/// # Panics
+/// If the range is out of bounds.
+#[track_caller]
+pub const unsafe fn slice_index_nonnull<T, R>(slice: NonNull<[T]>, range: R) -> NonNull<[T]>
+where
+    R: RangeIndex,
+{
+    // SAFETY: Guaranteed by caller. An in-bounds offset of a non-null pointer is non-null.
+    unsafe { NonNull::new_unchecked(slice_index_mut_ptr(slice.as_ptr(), range)) }
 }
 
 /// Const equivalent of [`<[T]>::get`](slice::get) for ranges.
@@ -222,7 +358,7 @@ where
     I: RangeIndex,
 {
     // SAFETY: `slice` comes from a reference and the resulting pointer is a valid
-    // subslice with the same mutability
+    // subslice, which can be reborrowed with the same mutability
     unsafe {
         match slice_get_nonnull(nonnull_from(slice), index) {
             Some(r) => Some(r.as_ref()),
@@ -230,13 +366,14 @@ where
         }
     }
 }
-/// Const equivalent of [`<[T]>::index`](slice::index) for ranges.
+/// Const equivalent of [`<[T]>::index`](core::ops::Index::index) for ranges.
+#[track_caller]
 pub const fn slice_index<T, I>(slice: &[T], index: I) -> &[T]
 where
     I: RangeIndex,
 {
     // SAFETY: `slice` comes from a reference and the resulting pointer is a valid
-    // subslice with the same mutability
+    // subslice, which can be reborrowed with the same mutability
     unsafe { slice_index_nonnull(nonnull_from(slice), index).as_ref() }
 }
 
@@ -246,7 +383,7 @@ where
     I: RangeIndex,
 {
     // SAFETY: `slice` comes from a reference and the resulting pointer is a valid
-    // subslice with the same mutability
+    // subslice, which can be reborrowed with the same mutability
     unsafe {
         match slice_get_nonnull(nonnull_from(slice), index) {
             Some(mut r) => Some(r.as_mut()),
@@ -255,16 +392,367 @@ where
     }
 }
 
-/// Const equivalent of [`<[T]>::index_mut`](slice::index_mut) for ranges.
+/// Const equivalent of [`<[T]>::index_mut`](core::ops::IndexMut::index_mut) for ranges.
+#[track_caller]
 pub const fn slice_index_mut<T, I>(slice: &mut [T], index: I) -> &mut [T]
 where
     I: RangeIndex,
 {
     // SAFETY: `slice` comes from a reference and the resulting pointer is a valid
-    // subslice with the same mutability
+    // subslice, which can be reborrowed with the same mutability
     unsafe { slice_index_nonnull(nonnull_from(slice), index).as_mut() }
 }
 
+/// Like [`slice_get`], but returns the reason for the range being invalid.
+///
+/// # Example
+/// ```
+/// use const_util::slice::{try_slice_index, SliceIndexError};
+/// const fn field(record: &[u8], start: usize, len: usize) -> Result<&[u8], SliceIndexError> {
+///     match start.checked_add(len) {
+///         Some(end) => try_slice_index(record, start..end),
+///         None => Err(SliceIndexError::Overflow),
+///     }
+/// }
+/// assert_eq!(field(b"abcd", 1, 2), Ok(&b"bc"[..]));
+/// assert_eq!(
+///     field(b"abcd", 3, 2),
+///     Err(SliceIndexError::EndOutOfRange { end: 5, len: 4 }),
+/// );
+/// ```
+pub const fn try_slice_index<T, I>(slice: &[T], index: I) -> Result<&[T], SliceIndexError>
+where
+    I: RangeIndex,
+{
+    match checked_range(index, slice.len()) {
+        // SAFETY: `slice` comes from a reference, the range was checked and the resulting pointer
+        // is a valid subslice, which can be reborrowed with the same mutability
+        Ok(range) => Ok(unsafe { &*subslice_ptr(nonnull_from(slice).as_ptr(), range) }),
+        Err(err) => Err(err),
+    }
+}
+
+/// Like [`slice_get_mut`], but returns the reason for the range being invalid.
+pub const fn try_slice_index_mut<T, I>(
+    slice: &mut [T],
+    index: I,
+) -> Result<&mut [T], SliceIndexError>
+where
+    I: RangeIndex,
+{
+    match checked_range(index, slice.len()) {
+        // SAFETY: `slice` comes from a reference, the range was checked and the resulting pointer
+        // is a valid subslice, which can be reborrowed with the same mutability
+        Ok(range) => Ok(unsafe { &mut *subslice_ptr(nonnull_from(slice).as_ptr(), range) }),
+        Err(err) => Err(err),
+    }
+}
+
+/// # Safety
+/// See [`slice_get_unchecked`]. Additionally, `slice` must satisfy the requirements of
+/// [`slice_get_mut_ptr`].
+#[track_caller]
+const unsafe fn slice_get_unchecked_ptr<T, I>(slice: *mut [T], index: I) -> *mut [T]
+where
+    I: RangeIndex,
+{
+    #[cfg(any(debug_assertions, feature = "checked"))]
+    {
+        // SAFETY: Guaranteed by caller
+        unsafe { slice_index_mut_ptr(slice, index) }
+    }
+    #[cfg(not(any(debug_assertions, feature = "checked")))]
+    {
+        let Some(Range { start, end }) = into_range(index, slice.len()) else {
+            // SAFETY: The caller guarantees that the range does not overflow
+            unsafe { core::hint::unreachable_unchecked() }
+        };
+        // SAFETY: The caller guarantees `start <= end <= slice.len()`, so the pointer addition is
+        // in-bounds, the subtraction does not overflow and the result is a subrange of `slice`.
+        unsafe {
+            core::ptr::slice_from_raw_parts_mut(
+                slice.cast::<T>().add(start),
+                end.unchecked_sub(start),
+            )
+        }
+    }
+}
+
+/// Const equivalent of [`<[T]>::get_unchecked`](slice::get_unchecked) for ranges.
+///
+/// If `debug_assertions` or the `checked` feature are enabled, the range is validated anyway and
+/// an invalid range causes the same panic as [`slice_index`].
+///
+/// # Safety
+/// `index` must be in bounds for `slice`, i.e. [`slice_get`] must return `Some` for it.
+///
+/// # Example
+/// ```
+/// use const_util::slice::slice_get_unchecked;
+/// const fn tail(slice: &[u8]) -> &[u8] {
+///     if slice.is_empty() {
+///         return slice;
+///     }
+///     // SAFETY: `slice` is not empty
+///     unsafe { slice_get_unchecked(slice, 1..) }
+/// }
+/// assert_eq!(tail(b"abc"), b"bc");
+/// ```
+#[track_caller]
+pub const unsafe fn slice_get_unchecked<T, I>(slice: &[T], index: I) -> &[T]
+where
+    I: RangeIndex,
+{
+    // SAFETY: `slice` comes from a reference, the caller guarantees that `index` is in bounds and
+    // the resulting pointer is a valid subslice, which can be reborrowed with the same mutability
+    unsafe { &*slice_get_unchecked_ptr(nonnull_from(slice).as_ptr(), index) }
+}
+
+/// Const equivalent of [`<[T]>::get_unchecked_mut`](slice::get_unchecked_mut) for ranges.
+///
+/// If `debug_assertions` or the `checked` feature are enabled, the range is validated anyway and
+/// an invalid range causes the same panic as [`slice_index_mut`].
+///
+/// # Safety
+/// `index` must be in bounds for `slice`, i.e. [`slice_get_mut`] must return `Some` for it.
+#[track_caller]
+pub const unsafe fn slice_get_unchecked_mut<T, I>(slice: &mut [T], index: I) -> &mut [T]
+where
+    I: RangeIndex,
+{
+    // SAFETY: `slice` comes from a reference, the caller guarantees that `index` is in bounds and
+    // the resulting pointer is a valid subslice, which can be reborrowed with the same mutability
+    unsafe { &mut *slice_get_unchecked_ptr(nonnull_from(slice).as_ptr(), index) }
+}
+
+/// Const equivalent of [`core::slice::Chunks`].
+///
+/// Created using [`Chunks::new`]. Can be used in `const` contexts using
+/// `while let Some(chunk) = chunks.next()`.
+///
+/// # Example
+/// ```
+/// use const_util::slice::Chunks;
+/// const fn sum_chunks<const N: usize>(slice: &[i32]) -> [i32; N] {
+///     let mut out = [0; N];
+///     let mut chunks = Chunks::new(slice, 2);
+///     let mut i = 0;
+///     while let Some(mut chunk) = chunks.next() {
+///         while let [first, rest @ ..] = chunk {
+///             out[i] += *first;
+///             chunk = rest;
+///         }
+///         i += 1;
+///     }
+///     out
+/// }
+/// assert_eq!(sum_chunks::<3>(&[1, 2, 3, 4, 5]), [3, 7, 5]);
+/// ```
+#[derive(Debug, Clone)]
+pub struct Chunks<'a, T> {
+    slice: &'a [T],
+    chunk_size: usize,
+}
+impl<'a, T> Chunks<'a, T> {
+    /// Equivalent of [`<[T]>::chunks`](slice::chunks).
+    ///
+    /// # Panics
+    /// If `chunk_size` is zero.
+    #[track_caller]
+    pub const fn new(slice: &'a [T], chunk_size: usize) -> Self {
+        assert!(chunk_size != 0, "chunk size must be non-zero");
+        Self { slice, chunk_size }
+    }
+    /// Const equivalent of [`Iterator::next`].
+    #[allow(clippy::should_implement_trait)]
+    pub const fn next(&mut self) -> Option<&'a [T]> {
+        if self.slice.is_empty() {
+            return None;
+        }
+        let mid = if self.chunk_size < self.slice.len() {
+            self.chunk_size
+        } else {
+            self.slice.len()
+        };
+        let (chunk, rest) = self.slice.split_at(mid);
+        self.slice = rest;
+        Some(chunk)
+    }
+}
+impl<'a, T> Iterator for Chunks<'a, T> {
+    type Item = &'a [T];
+    fn next(&mut self) -> Option<Self::Item> {
+        Self::next(self)
+    }
+}
+
+/// Const equivalent of [`core::slice::ChunksExact`].
+///
+/// Created using [`ChunksExact::new`]. Can be used in `const` contexts using
+/// `while let Some(chunk) = chunks.next()`.
+///
+/// # Example
+/// ```
+/// use const_util::slice::ChunksExact;
+/// let mut chunks = ChunksExact::new(&[1, 2, 3, 4, 5], 2);
+/// assert_eq!(chunks.next(), Some(&[1, 2][..]));
+/// assert_eq!(chunks.next(), Some(&[3, 4][..]));
+/// assert_eq!(chunks.next(), None);
+/// assert_eq!(chunks.remainder(), &[5]);
+/// ```
+#[derive(Debug, Clone)]
+pub struct ChunksExact<'a, T> {
+    slice: &'a [T],
+    rem: &'a [T],
+    chunk_size: usize,
+}
+impl<'a, T> ChunksExact<'a, T> {
+    /// Equivalent of [`<[T]>::chunks_exact`](slice::chunks_exact).
+    ///
+    /// # Panics
+    /// If `chunk_size` is zero.
+    #[track_caller]
+    pub const fn new(slice: &'a [T], chunk_size: usize) -> Self {
+        assert!(chunk_size != 0, "chunk size must be non-zero");
+        let (slice, rem) = slice.split_at(slice.len() - slice.len() % chunk_size);
+        Self {
+            slice,
+            rem,
+            chunk_size,
+        }
+    }
+    /// Const equivalent of [`core::slice::ChunksExact::remainder`].
+    pub const fn remainder(&self) -> &'a [T] {
+        self.rem
+    }
+    /// Const equivalent of [`Iterator::next`].
+    #[allow(clippy::should_implement_trait)]
+    pub const fn next(&mut self) -> Option<&'a [T]> {
+        if self.slice.is_empty() {
+            return None;
+        }
+        let (chunk, rest) = self.slice.split_at(self.chunk_size);
+        self.slice = rest;
+        Some(chunk)
+    }
+}
+impl<'a, T> Iterator for ChunksExact<'a, T> {
+    type Item = &'a [T];
+    fn next(&mut self) -> Option<Self::Item> {
+        Self::next(self)
+    }
+}
+
+/// Const equivalent of [`core::slice::Windows`].
+///
+/// Created using [`Windows::new`]. Can be used in `const` contexts using
+/// `while let Some(window) = windows.next()`.
+///
+/// # Example
+/// ```
+/// use const_util::slice::Windows;
+/// const fn is_sorted(slice: &[u32]) -> bool {
+///     let mut windows = Windows::new(slice, 2);
+///     while let Some(&[a, b]) = windows.next() {
+///         if a > b {
+///             return false;
+///         }
+///     }
+///     true
+/// }
+/// assert!(is_sorted(&[1, 2, 2, 5]));
+/// assert!(!is_sorted(&[1, 3, 2]));
+/// ```
+#[derive(Debug, Clone)]
+pub struct Windows<'a, T> {
+    slice: &'a [T],
+    size: usize,
+}
+impl<'a, T> Windows<'a, T> {
+    /// Equivalent of [`<[T]>::windows`](slice::windows).
+    ///
+    /// # Panics
+    /// If `size` is zero.
+    #[track_caller]
+    pub const fn new(slice: &'a [T], size: usize) -> Self {
+        assert!(size != 0, "window size must be non-zero");
+        Self { slice, size }
+    }
+    /// Const equivalent of [`Iterator::next`].
+    #[allow(clippy::should_implement_trait)]
+    pub const fn next(&mut self) -> Option<&'a [T]> {
+        if self.size > self.slice.len() {
+            return None;
+        }
+        let window = self.slice.split_at(self.size).0;
+        if let [_, rest @ ..] = self.slice {
+            self.slice = rest;
+        }
+        Some(window)
+    }
+}
+impl<'a, T> Iterator for Windows<'a, T> {
+    type Item = &'a [T];
+    fn next(&mut self) -> Option<Self::Item> {
+        Self::next(self)
+    }
+}
+
+/// Const equivalent of [`core::slice::Split`] for byte slices.
+///
+/// Since `PartialEq` and closures cannot be used in `const` contexts, this only supports splitting
+/// `[u8]` on a single separator byte.
+///
+/// # Example
+/// ```
+/// use const_util::slice::Split;
+/// let mut split = Split::new(b"a,,bc", b',');
+/// assert_eq!(split.next(), Some(&b"a"[..]));
+/// assert_eq!(split.next(), Some(&b""[..]));
+/// assert_eq!(split.next(), Some(&b"bc"[..]));
+/// assert_eq!(split.next(), None);
+/// ```
+#[derive(Debug, Clone)]
+pub struct Split<'a> {
+    slice: &'a [u8],
+    sep: u8,
+    finished: bool,
+}
+impl<'a> Split<'a> {
+    /// Equivalent of `slice.split(|&b| b == sep)`.
+    pub const fn new(slice: &'a [u8], sep: u8) -> Self {
+        Self {
+            slice,
+            sep,
+            finished: false,
+        }
+    }
+    /// Const equivalent of [`Iterator::next`].
+    #[allow(clippy::should_implement_trait)]
+    pub const fn next(&mut self) -> Option<&'a [u8]> {
+        if self.finished {
+            return None;
+        }
+        let mut i = 0;
+        while i < self.slice.len() {
+            if self.slice[i] == self.sep {
+                let (item, rest) = self.slice.split_at(i);
+                self.slice = rest.split_at(1).1;
+                return Some(item);
+            }
+            i += 1;
+        }
+        self.finished = true;
+        Some(self.slice)
+    }
+}
+impl<'a> Iterator for Split<'a> {
+    type Item = &'a [u8];
+    fn next(&mut self) -> Option<Self::Item> {
+        Self::next(self)
+    }
+}
+
 #[test]
 fn test() {
     let mut example: Vec<i32> = (0..20).collect();
@@ -282,6 +770,48 @@ fn test() {
                 .unwrap_err();
             }
             assert_eq!(slice_get_mut(example, i..j), example2.get_mut(i..j));
+            assert_eq!(
+                try_slice_index_mut(example, i..j).ok(),
+                example2.get_mut(i..j)
+            );
         }
     }
 }
+
+#[test]
+#[should_panic = "range end index 12 is out of range for slice of length 3"]
+fn test_panic_msg() {
+    slice_index(&[1, 2, 3], 1..12);
+}
+
+// Multi-digit numbers used to be rendered with their digits reversed
+#[test]
+#[should_panic = "range end index 123 is out of range for slice of length 45"]
+fn test_panic_msg_multi_digit() {
+    slice_index(&[0; 45], 1..123);
+}
+
+#[cfg(any(debug_assertions, feature = "checked"))]
+#[test]
+#[should_panic = "slice index starts at 2 but ends at 1"]
+fn test_unchecked_checked() {
+    // SAFETY: Not actually sound, but the range is checked in this configuration
+    unsafe { slice_get_unchecked(&[1, 2, 3], (Bound::Included(2), Bound::Excluded(1))) };
+}
+
+#[test]
+fn test_iters() {
+    let v: Vec<i32> = (0..7).collect();
+    for n in 1..9 {
+        assert!(Chunks::new(&v, n).eq(v.chunks(n)));
+        assert!(ChunksExact::new(&v, n).eq(v.chunks_exact(n)));
+        assert_eq!(
+            ChunksExact::new(&v, n).remainder(),
+            v.chunks_exact(n).remainder()
+        );
+        assert!(Windows::new(&v, n).eq(v.windows(n)));
+    }
+    for s in [&b""[..], b",", b"a,b", b",a,,b,"] {
+        assert!(Split::new(s, b',').eq(s.split(|&b| b == b',')));
+    }
+}
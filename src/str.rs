@@ -0,0 +1,340 @@
+//! Const variants of functions for dealing with `str`s
+//!
+//! The iterator types in this module have a `const fn next`, meaning that they can be used in
+//! `const` contexts using `while let Some(item) = iter.next()`. They also implement [`Iterator`]
+//! for use at runtime.
+
+/// Splits `s` at the byte index `mid`.
+///
+/// # Safety
+/// `mid` must lie on a char boundary of `s`.
+pub(crate) const unsafe fn split_at_unchecked(s: &str, mid: usize) -> (&str, &str) {
+    let (lhs, rhs) = s.as_bytes().split_at(mid);
+    // SAFETY: `mid` is a char boundary, so both halves are valid UTF-8
+    unsafe {
+        (
+            core::str::from_utf8_unchecked(lhs),
+            core::str::from_utf8_unchecked(rhs),
+        )
+    }
+}
+
+/// Decodes the first `char` of `s`, returning it together with its length in bytes.
+const fn first_char(s: &str) -> Option<(char, usize)> {
+    const fn cont(b: u8) -> u32 {
+        (b & 0x3F) as u32
+    }
+    let (code, len) = match *s.as_bytes() {
+        [] => return None,
+        [a, ..] if a < 0x80 => (a as u32, 1),
+        [a, b, ..] if a < 0xE0 => (((a & 0x1F) as u32) << 6 | cont(b), 2),
+        [a, b, c, ..] if a < 0xF0 => (((a & 0x0F) as u32) << 12 | cont(b) << 6 | cont(c), 3),
+        [a, b, c, d, ..] => (
+            ((a & 0x07) as u32) << 18 | cont(b) << 12 | cont(c) << 6 | cont(d),
+            4,
+        ),
+        _ => unreachable!(),
+    };
+    match char::from_u32(code) {
+        Some(c) => Some((c, len)),
+        None => unreachable!(),
+    }
+}
+
+/// Const version of [`char::is_whitespace`], which is only const since 1.87.
+const fn is_whitespace(c: char) -> bool {
+    matches!(
+        c,
+        '\t'..='\r'
+            | ' '
+            | '\u{85}'
+            | '\u{A0}'
+            | '\u{1680}'
+            | '\u{2000}'..='\u{200A}'
+            | '\u{2028}'
+            | '\u{2029}'
+            | '\u{202F}'
+            | '\u{205F}'
+            | '\u{3000}'
+    )
+}
+
+/// Finds the byte index of the first occurrence of `pat` in `s`.
+const fn find(s: &[u8], pat: &[u8]) -> Option<usize> {
+    let mut i = 0;
+    while i + pat.len() <= s.len() {
+        let mut j = 0;
+        while j < pat.len() && s[i + j] == pat[j] {
+            j += 1;
+        }
+        if j == pat.len() {
+            return Some(i);
+        }
+        i += 1;
+    }
+    None
+}
+
+macro_rules! impl_iterator {
+    ($ty:ident, $item:ty) => {
+        impl<'a> Iterator for $ty<'a> {
+            type Item = $item;
+            fn next(&mut self) -> Option<Self::Item> {
+                Self::next(self)
+            }
+        }
+    };
+}
+
+/// Const equivalent of [`core::str::Chars`].
+///
+/// # Example
+/// ```
+/// use const_util::str::Chars;
+/// const fn count_chars(s: &str) -> usize {
+///     let mut chars = Chars::new(s);
+///     let mut n = 0;
+///     while let Some(_) = chars.next() {
+///         n += 1;
+///     }
+///     n
+/// }
+/// assert_eq!(count_chars("Grüße"), 5);
+/// ```
+#[derive(Debug, Clone)]
+pub struct Chars<'a> {
+    rest: &'a str,
+}
+impl<'a> Chars<'a> {
+    /// Equivalent of [`str::chars`].
+    pub const fn new(s: &'a str) -> Self {
+        Self { rest: s }
+    }
+    /// Const equivalent of [`core::str::Chars::as_str`].
+    pub const fn as_str(&self) -> &'a str {
+        self.rest
+    }
+    /// Const equivalent of [`Iterator::next`].
+    #[allow(clippy::should_implement_trait)]
+    pub const fn next(&mut self) -> Option<char> {
+        let Some((c, len)) = first_char(self.rest) else {
+            return None;
+        };
+        // SAFETY: `len` is the length of the first char
+        self.rest = unsafe { split_at_unchecked(self.rest, len).1 };
+        Some(c)
+    }
+}
+impl_iterator!(Chars, char);
+
+/// Const equivalent of [`core::str::CharIndices`].
+///
+/// # Example
+/// ```
+/// use const_util::str::CharIndices;
+/// let mut it = CharIndices::new("aä");
+/// assert_eq!(it.next(), Some((0, 'a')));
+/// assert_eq!(it.next(), Some((1, 'ä')));
+/// assert_eq!(it.next(), None);
+/// ```
+#[derive(Debug, Clone)]
+pub struct CharIndices<'a> {
+    offset: usize,
+    chars: Chars<'a>,
+}
+impl<'a> CharIndices<'a> {
+    /// Equivalent of [`str::char_indices`].
+    pub const fn new(s: &'a str) -> Self {
+        Self {
+            offset: 0,
+            chars: Chars::new(s),
+        }
+    }
+    /// Const equivalent of [`core::str::CharIndices::offset`].
+    pub const fn offset(&self) -> usize {
+        self.offset
+    }
+    /// Const equivalent of [`core::str::CharIndices::as_str`].
+    pub const fn as_str(&self) -> &'a str {
+        self.chars.as_str()
+    }
+    /// Const equivalent of [`Iterator::next`].
+    #[allow(clippy::should_implement_trait)]
+    pub const fn next(&mut self) -> Option<(usize, char)> {
+        let Some(c) = self.chars.next() else {
+            return None;
+        };
+        let offset = self.offset;
+        self.offset += c.len_utf8();
+        Some((offset, c))
+    }
+}
+impl_iterator!(CharIndices, (usize, char));
+
+/// Const equivalent of [`core::str::Split`] with a `&str` pattern.
+///
+/// # Example
+/// ```
+/// use const_util::str::Split;
+/// let mut split = Split::new("a::b::", "::");
+/// assert_eq!(split.next(), Some("a"));
+/// assert_eq!(split.next(), Some("b"));
+/// assert_eq!(split.next(), Some(""));
+/// assert_eq!(split.next(), None);
+/// ```
+#[derive(Debug, Clone)]
+pub struct Split<'a> {
+    rest: &'a str,
+    pat: &'a str,
+    finished: bool,
+}
+impl<'a> Split<'a> {
+    /// Equivalent of [`str::split`] with a `&str` pattern.
+    ///
+    /// # Panics
+    /// If `pat` is empty.
+    #[track_caller]
+    pub const fn new(s: &'a str, pat: &'a str) -> Self {
+        assert!(!pat.is_empty(), "split pattern must be non-empty");
+        Self {
+            rest: s,
+            pat,
+            finished: false,
+        }
+    }
+    /// Const equivalent of [`Iterator::next`].
+    #[allow(clippy::should_implement_trait)]
+    pub const fn next(&mut self) -> Option<&'a str> {
+        if self.finished {
+            return None;
+        }
+        let Some(i) = find(self.rest.as_bytes(), self.pat.as_bytes()) else {
+            self.finished = true;
+            return Some(self.rest);
+        };
+        // SAFETY: `i` and `i + pat.len()` are the boundaries of a match of a valid `str`, so they
+        // lie on char boundaries
+        unsafe {
+            let (item, rest) = split_at_unchecked(self.rest, i);
+            self.rest = split_at_unchecked(rest, self.pat.len()).1;
+            Some(item)
+        }
+    }
+}
+impl_iterator!(Split, &'a str);
+
+/// Const equivalent of [`core::str::Lines`].
+///
+/// # Example
+/// ```
+/// use const_util::str::Lines;
+/// let mut lines = Lines::new("a\r\n\nb\n");
+/// assert_eq!(lines.next(), Some("a"));
+/// assert_eq!(lines.next(), Some(""));
+/// assert_eq!(lines.next(), Some("b"));
+/// assert_eq!(lines.next(), None);
+/// ```
+#[derive(Debug, Clone)]
+pub struct Lines<'a> {
+    rest: &'a str,
+}
+impl<'a> Lines<'a> {
+    /// Equivalent of [`str::lines`].
+    pub const fn new(s: &'a str) -> Self {
+        Self { rest: s }
+    }
+    /// Const equivalent of [`Iterator::next`].
+    #[allow(clippy::should_implement_trait)]
+    pub const fn next(&mut self) -> Option<&'a str> {
+        if self.rest.is_empty() {
+            return None;
+        }
+        let Some(i) = find(self.rest.as_bytes(), b"\n") else {
+            let line = self.rest;
+            self.rest = "";
+            return Some(line);
+        };
+        // SAFETY: `i` and `i + 1` are the boundaries of an ASCII character
+        let (mut line, rest) = unsafe { split_at_unchecked(self.rest, i) };
+        // SAFETY: See above
+        self.rest = unsafe { split_at_unchecked(rest, 1).1 };
+        if let [.., b'\r'] = line.as_bytes() {
+            // SAFETY: The last character is ASCII
+            line = unsafe { split_at_unchecked(line, line.len() - 1).0 };
+        }
+        Some(line)
+    }
+}
+impl_iterator!(Lines, &'a str);
+
+/// Const equivalent of [`core::str::SplitWhitespace`].
+///
+/// # Example
+/// ```
+/// use const_util::str::SplitWhitespace;
+/// let mut words = SplitWhitespace::new("  a\tbc \n d ");
+/// assert_eq!(words.next(), Some("a"));
+/// assert_eq!(words.next(), Some("bc"));
+/// assert_eq!(words.next(), Some("d"));
+/// assert_eq!(words.next(), None);
+/// ```
+#[derive(Debug, Clone)]
+pub struct SplitWhitespace<'a> {
+    rest: &'a str,
+}
+impl<'a> SplitWhitespace<'a> {
+    /// Equivalent of [`str::split_whitespace`].
+    pub const fn new(s: &'a str) -> Self {
+        Self { rest: s }
+    }
+    /// Const equivalent of [`Iterator::next`].
+    #[allow(clippy::should_implement_trait)]
+    pub const fn next(&mut self) -> Option<&'a str> {
+        // Skip leading whitespace
+        let mut chars = Chars::new(self.rest);
+        let mut start = chars.as_str();
+        while let Some(c) = chars.next() {
+            if !is_whitespace(c) {
+                break;
+            }
+            start = chars.as_str();
+        }
+        if start.is_empty() {
+            self.rest = start;
+            return None;
+        }
+        // Find the end of the word
+        let mut chars = Chars::new(start);
+        let mut end = 0;
+        while let Some(c) = chars.next() {
+            if is_whitespace(c) {
+                break;
+            }
+            end += c.len_utf8();
+        }
+        // SAFETY: `end` is the sum of the lengths of the chars preceding it
+        let (word, rest) = unsafe { split_at_unchecked(start, end) };
+        self.rest = rest;
+        Some(word)
+    }
+}
+impl_iterator!(SplitWhitespace, &'a str);
+
+#[test]
+fn test() {
+    for s in [
+        "",
+        "\n",
+        "a\r\nb",
+        "a\r",
+        "\u{3000}Grüße,\u{a0}\u{1F600} x\n\n y,",
+        " , ,,",
+    ] {
+        assert!(Chars::new(s).eq(s.chars()));
+        assert!(CharIndices::new(s).eq(s.char_indices()));
+        assert!(Lines::new(s).eq(s.lines()));
+        assert!(SplitWhitespace::new(s).eq(s.split_whitespace()));
+        assert!(Split::new(s, ",").eq(s.split(",")));
+        assert!(Split::new(s, ", ").eq(s.split(", ")));
+    }
+}
@@ -0,0 +1,372 @@
+//! Functions for reshaping tuples by value
+//!
+//! The functions in this module move the elements of tuples with up to 12 elements into new
+//! tuples, regardless of them having drop glue. They are usually used through the macros
+//! [`tuple_append!`], [`tuple_prepend!`], [`tuple_concat!`], [`tuple_split_at!`] and
+//! [`tuple_swap!`].
+//!
+//! The resulting tuple types are computed using the traits in this module, which are implemented
+//! for all tuples with up to 12 elements.
+//!
+//! [`tuple_append!`]: crate::tuple_append
+//! [`tuple_prepend!`]: crate::tuple_prepend
+//! [`tuple_concat!`]: crate::tuple_concat
+//! [`tuple_split_at!`]: crate::tuple_split_at
+//! [`tuple_swap!`]: crate::tuple_swap
+
+use core::{
+    mem::{offset_of, ManuallyDrop, MaybeUninit},
+    ptr,
+};
+
+mod hidden {
+    pub trait Sealed {}
+}
+
+/// A tuple with up to 12 elements.
+///
+/// This trait and the other traits in this module are sealed and cannot be implemented outside of
+/// this crate.
+pub trait Tuple: Sized + hidden::Sealed {
+    /// The number of elements.
+    const LEN: usize = Self::SIZES.len();
+    /// The offsets of the fields of `Self`, in order.
+    #[doc(hidden)]
+    const OFFSETS: &'static [usize];
+    /// The sizes of the fields of `Self`, in order.
+    #[doc(hidden)]
+    const SIZES: &'static [usize];
+}
+/// Tuples that can be concatenated with `U`.
+///
+/// `Output` consists of the fields of `Self` followed by the fields of `U`.
+pub trait TupleConcat<U: Tuple>: Tuple {
+    /// The concatenated tuple.
+    type Output: Tuple;
+}
+/// Tuples that can be split at index `I`.
+///
+/// `Left` consists of the first `I` fields of `Self` and `Right` of the remaining ones.
+pub trait TupleSplitAt<const I: usize>: Tuple {
+    /// The tuple of the first `I` elements.
+    type Left: Tuple;
+    /// The tuple of the remaining elements.
+    type Right: Tuple;
+}
+/// Tuples with an element at index `I`.
+pub trait TupleGet<const I: usize>: Tuple {
+    /// The type of the element.
+    type Elem;
+}
+/// Tuples whose element at index `I` can be replaced with a `V`.
+///
+/// `Output` consists of the fields of `Self`, except that field `I` has type `V`.
+pub trait TupleReplace<const I: usize, V>: TupleGet<I> {
+    /// The tuple with the element replaced.
+    type Output: Tuple;
+}
+/// Tuples whose elements at indices `I` and `J` can be swapped.
+///
+/// `Output` consists of the fields of `Self`, with the types of fields `I` and `J` swapped.
+pub trait TupleSwap<const I: usize, const J: usize>: Tuple {
+    /// The tuple with the elements swapped.
+    type Output: Tuple;
+}
+
+// Replacing field `I` with the type of field `J` and then field `J` with the type of field `I`
+// swaps them
+impl<T, const I: usize, const J: usize> TupleSwap<I, J> for T
+where
+    T: TupleGet<I> + TupleGet<J> + TupleReplace<I, <T as TupleGet<J>>::Elem>,
+    <T as TupleReplace<I, <T as TupleGet<J>>::Elem>>::Output:
+        TupleReplace<J, <T as TupleGet<I>>::Elem>,
+{
+    type Output = <<T as TupleReplace<I, <T as TupleGet<J>>::Elem>>::Output as TupleReplace<
+        J,
+        <T as TupleGet<I>>::Elem,
+    >>::Output;
+}
+
+/// Implements the traits for all prefixes of the given list of type parameters and indices.
+///
+/// The last list contains the type parameters available for the right-hand side of a
+/// concatenation, such that the result has at most 12 elements.
+macro_rules! impl_tuples {
+    ([$($done:ident $di:tt)*] [] [$($budget:ident)*]) => {
+        impl_tuples!(@one [$($done $di)*] [$($budget)*]);
+    };
+    (
+        [$($done:ident $di:tt)*] [$next:ident $ni:tt $($rest:tt)*]
+        [$_drop:ident $($budget:ident)*]
+    ) => {
+        impl_tuples!(@one [$($done $di)*] [$_drop $($budget)*]);
+        impl_tuples!([$($done $di)* $next $ni] [$($rest)*] [$($budget)*]);
+    };
+    (@one [$($t:ident $i:tt)*] [$($budget:ident)*]) => {
+        impl<$($t),*> hidden::Sealed for ($($t,)*) {}
+        impl<$($t),*> Tuple for ($($t,)*) {
+            const OFFSETS: &'static [usize] = &[$(offset_of!(Self, $i)),*];
+            const SIZES: &'static [usize] = &[$(size_of::<$t>()),*];
+        }
+        impl_tuples!(@concat [$($t)*] [] [$($budget)*]);
+        impl_tuples!(@split [] [$($t)*] [0 $(, $i + 1)*]);
+        impl_tuples!(@elem [] [$($t)*] [$($i)*]);
+    };
+    (@concat [$($l:ident)*] [$($r:ident)*] [$($budget:ident)*]) => {
+        impl<$($l,)* $($r,)*> TupleConcat<($($r,)*)> for ($($l,)*) {
+            type Output = ($($l,)* $($r,)*);
+        }
+        impl_tuples!(@concat_next [$($l)*] [$($r)*] [$($budget)*]);
+    };
+    (@concat_next $l:tt $r:tt []) => {};
+    (@concat_next $l:tt [$($r:ident)*] [$next:ident $($budget:ident)*]) => {
+        impl_tuples!(@concat $l [$($r)* $next] [$($budget)*]);
+    };
+    (@split [$($l:ident)*] [$($r:ident)*] [$idx:expr $(, $idxs:expr)*]) => {
+        impl<$($l,)* $($r,)*> TupleSplitAt<{ $idx }> for ($($l,)* $($r,)*) {
+            type Left = ($($l,)*);
+            type Right = ($($r,)*);
+        }
+        impl_tuples!(@split_next [$($l)*] [$($r)*] [$($idxs),*]);
+    };
+    (@split_next $l:tt [] $idxs:tt) => {};
+    (@split_next [$($l:ident)*] [$next:ident $($r:ident)*] $idxs:tt) => {
+        impl_tuples!(@split [$($l)* $next] [$($r)*] $idxs);
+    };
+    (@elem $l:tt [] []) => {};
+    (@elem [$($l:ident)*] [$cur:ident $($r:ident)*] [$idx:tt $($idxs:tt)*]) => {
+        impl<$($l,)* $cur, $($r,)*> TupleGet<$idx> for ($($l,)* $cur, $($r,)*) {
+            type Elem = $cur;
+        }
+        impl<$($l,)* $cur, $($r,)* V> TupleReplace<$idx, V> for ($($l,)* $cur, $($r,)*) {
+            type Output = ($($l,)* V, $($r,)*);
+        }
+        impl_tuples!(@elem [$($l)* $cur] [$($r)*] [$($idxs)*]);
+    };
+}
+impl_tuples!(
+    []
+    [A0 0 A1 1 A2 2 A3 3 A4 4 A5 5 A6 6 A7 7 A8 8 A9 9 A10 10 A11 11]
+    [B0 B1 B2 B3 B4 B5 B6 B7 B8 B9 B10 B11]
+);
+
+/// Moves field `from` of `src` to field `to` of `dst`.
+///
+/// # Safety
+/// The fields must have the same type, `src` must be valid for reads and `dst` for writes. The
+/// moved value must not be used through `src` afterwards.
+const unsafe fn move_field<S: Tuple, D: Tuple>(src: &S, from: usize, dst: *mut D, to: usize) {
+    // SAFETY: Guaranteed by the caller. `Tuple` is sealed and its offsets and sizes are those of
+    // the fields.
+    unsafe {
+        ptr::copy_nonoverlapping(
+            ptr::from_ref(src).cast::<u8>().add(S::OFFSETS[from]),
+            dst.cast::<u8>().add(D::OFFSETS[to]),
+            S::SIZES[from],
+        )
+    }
+}
+
+/// Concatenates two tuples.
+///
+/// # Example
+/// ```
+/// use const_util::tuple::concat;
+/// assert_eq!(concat((1, "a"), (String::new(),)), (1, "a", String::new()));
+/// ```
+pub const fn concat<T: TupleConcat<U>, U: Tuple>(lhs: T, rhs: U) -> T::Output {
+    let lhs = ManuallyDrop::new(lhs);
+    let rhs = ManuallyDrop::new(rhs);
+    let (lhs, rhs) = (
+        crate::mem::man_drop_ref(&lhs),
+        crate::mem::man_drop_ref(&rhs),
+    );
+    let mut out = MaybeUninit::<T::Output>::uninit();
+    let mut i = 0;
+    while i < T::LEN {
+        // SAFETY: The first fields of the output are those of `lhs`, which is forgotten
+        unsafe { move_field(lhs, i, out.as_mut_ptr(), i) };
+        i += 1;
+    }
+    let mut i = 0;
+    while i < U::LEN {
+        // SAFETY: The remaining fields of the output are those of `rhs`, which is forgotten
+        unsafe { move_field(rhs, i, out.as_mut_ptr(), T::LEN + i) };
+        i += 1;
+    }
+    // SAFETY: All fields were initialized
+    unsafe { out.assume_init() }
+}
+
+/// Splits a tuple into the tuple of its first `I` elements and the tuple of the remaining ones.
+///
+/// # Example
+/// ```
+/// use const_util::tuple::split_at;
+/// assert_eq!(split_at::<_, 1>((1, "a", 'b')), ((1,), ("a", 'b')));
+/// ```
+pub const fn split_at<T: TupleSplitAt<I>, const I: usize>(tup: T) -> (T::Left, T::Right) {
+    let tup = ManuallyDrop::new(tup);
+    let tup = crate::mem::man_drop_ref(&tup);
+    let mut left = MaybeUninit::<T::Left>::uninit();
+    let mut right = MaybeUninit::<T::Right>::uninit();
+    let mut i = 0;
+    while i < T::LEN {
+        // SAFETY: The fields are split after the first `I` and `tup` is forgotten
+        unsafe {
+            if i < I {
+                move_field(tup, i, left.as_mut_ptr(), i)
+            } else {
+                move_field(tup, i, right.as_mut_ptr(), i - I)
+            }
+        }
+        i += 1;
+    }
+    // SAFETY: All fields were initialized
+    unsafe { (left.assume_init(), right.assume_init()) }
+}
+
+/// Swaps the elements of a tuple at indices `I` and `J`.
+///
+/// # Example
+/// ```
+/// use const_util::tuple::swap;
+/// assert_eq!(swap::<_, 0, 2>((1, "a", 'b')), ('b', "a", 1));
+/// ```
+pub const fn swap<T: TupleSwap<I, J>, const I: usize, const J: usize>(tup: T) -> T::Output {
+    let tup = ManuallyDrop::new(tup);
+    let tup = crate::mem::man_drop_ref(&tup);
+    let mut out = MaybeUninit::<T::Output>::uninit();
+    let mut i = 0;
+    while i < T::LEN {
+        let to = if i == I {
+            J
+        } else if i == J {
+            I
+        } else {
+            i
+        };
+        // SAFETY: The output has the types of the fields at `I` and `J` swapped and `tup` is
+        // forgotten
+        unsafe { move_field(tup, i, out.as_mut_ptr(), to) };
+        i += 1;
+    }
+    // SAFETY: All fields were initialized
+    unsafe { out.assume_init() }
+}
+
+/// Appends values to a tuple.
+///
+/// # Example
+/// ```
+/// use const_util::tuple_append;
+/// const fn push<A, B, C>(tup: (A, B), value: C) -> (A, B, C) {
+///     tuple_append!(tup, value)
+/// }
+/// assert_eq!(push((1, String::new()), 'c'), (1, String::new(), 'c'));
+/// assert_eq!(tuple_append!((1,), 2, 3), (1, 2, 3));
+/// ```
+#[macro_export]
+macro_rules! tuple_append {
+    ($tup:expr $(, $value:expr)+ $(,)?) => {
+        $crate::tuple::concat($tup, ($($value,)+))
+    };
+}
+
+/// Prepends values to a tuple.
+///
+/// The tuple is evaluated before the values.
+///
+/// # Example
+/// ```
+/// use const_util::tuple_prepend;
+/// const fn push_front<A, B, C>(tup: (A, B), value: C) -> (C, A, B) {
+///     tuple_prepend!(tup, value)
+/// }
+/// assert_eq!(push_front((1, String::new()), 'c'), ('c', 1, String::new()));
+/// assert_eq!(tuple_prepend!((3,), 1, 2), (1, 2, 3));
+/// ```
+#[macro_export]
+macro_rules! tuple_prepend {
+    ($tup:expr $(, $value:expr)+ $(,)?) => {
+        match $tup {
+            __tup => $crate::tuple::concat(($($value,)+), __tup),
+        }
+    };
+}
+
+/// Concatenates tuples.
+///
+/// # Example
+/// ```
+/// use const_util::tuple_concat;
+/// const fn join<A, B, C>(a: (A,), b: (B, C), c: ()) -> (A, B, C) {
+///     tuple_concat!(a, b, c)
+/// }
+/// assert_eq!(join((1,), (String::new(), 'c'), ()), (1, String::new(), 'c'));
+/// ```
+#[macro_export]
+macro_rules! tuple_concat {
+    ($tup:expr $(,)?) => {
+        $tup
+    };
+    ($lhs:expr, $rhs:expr $(, $rest:expr)* $(,)?) => {
+        $crate::tuple_concat!($crate::tuple::concat($lhs, $rhs) $(, $rest)*)
+    };
+}
+
+/// Splits a tuple into the tuple of the elements before the given index and the tuple of the
+/// remaining ones.
+///
+/// # Example
+/// ```
+/// use const_util::{destruct_tuple, tuple_split_at};
+/// const fn split<A, B, C>(tup: (A, B, C)) -> ((A,), (B, C)) {
+///     tuple_split_at!(tup, 1)
+/// }
+/// destruct_tuple! { head, tail in split((1, String::new(), 'c')) }
+/// assert_eq!(head, (1,));
+/// assert_eq!(tail, (String::new(), 'c'));
+/// ```
+#[macro_export]
+macro_rules! tuple_split_at {
+    ($tup:expr, $index:expr $(,)?) => {
+        $crate::tuple::split_at::<_, { $index }>($tup)
+    };
+}
+
+/// Swaps two elements of a tuple, given their indices.
+///
+/// # Example
+/// ```
+/// use const_util::tuple_swap;
+/// const fn rotate<A, B, C>(tup: (A, B, C)) -> (C, B, A) {
+///     tuple_swap!(tup, 0, 2)
+/// }
+/// assert_eq!(rotate((1, String::new(), 'c')), ('c', String::new(), 1));
+/// ```
+///
+/// Out of bounds indices fail to compile:
+/// ```compile_fail
+/// const_util::tuple_swap!((1, 2), 0, 2);
+/// ```
+#[macro_export]
+macro_rules! tuple_swap {
+    ($tup:expr, $i:expr, $j:expr $(,)?) => {
+        $crate::tuple::swap::<_, { $i }, { $j }>($tup)
+    };
+}
+
+#[test]
+fn test() {
+    const PADDED: (u8, u64, &str, u16, ()) = concat(swap::<_, 0, 1>((5u64, 1u8)), ("a", 2u16, ()));
+    assert_eq!(PADDED, (1, 5, "a", 2, ()));
+    let (lhs, rhs) = split_at::<_, 3>(PADDED);
+    assert_eq!((lhs, rhs), ((1, 5, "a"), (2, ())));
+
+    let strings = tuple_append!((String::from("a"), vec![1u32]), Box::new(2u8));
+    let strings = tuple_swap!(strings, 2, 0);
+    assert_eq!(strings, (Box::new(2), vec![1], String::from("a")));
+    let (empty, strings) = split_at::<_, 0>(strings);
+    assert_eq!(concat(empty, strings.clone()), strings);
+}